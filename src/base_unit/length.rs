@@ -7,31 +7,112 @@ use crate::{
 pub struct MeterBaseUnit;
 impl BaseUnitTag for MeterBaseUnit {
     type Dimension = LengthBaseDimension;
+    type Canonical = ConvInt<1>;
 }
 impl BaseUnitInfo for MeterBaseUnit {
     const NAME: Info = "meter";
     const SYMBOL: Info = "m";
+    const PREFIX: Option<i8> = Some(0);
 }
 
 pub struct YardBaseUnit;
 impl BaseUnitTag for YardBaseUnit {
     type Dimension = LengthBaseDimension;
+    type Canonical = ConvRatio<1143, 1250>;
 }
 impl BaseUnitInfo for YardBaseUnit {
     const NAME: Info = "yard";
     const SYMBOL: Info = "yd";
 }
 
-impl ConversionTo<MeterBaseUnit> for YardBaseUnit {
-    type Factor = ConvRatio<1143, 1250>;
+pub type FootBaseUnit = ScaledBaseUnit<YardBaseUnit, 1, 3>;
+impl BaseUnitInfo for FootBaseUnit {
+    const NAME: Info = "foot";
+    const SYMBOL: Info = "ft";
+    const PLURAL: Option<Info> = Some("feet");
 }
 
-impl ConversionTo<YardBaseUnit> for MeterBaseUnit {
-    type Factor = ConvRatio<1250, 1143>;
+/// Gunter's chain
+pub type ChainBaseUnit = ScaledBaseUnit<YardBaseUnit, 22>;
+impl BaseUnitInfo for ChainBaseUnit {
+    const NAME: Info = "chain";
+    const SYMBOL: Info = "ch";
 }
 
-pub type FootBaseUnit = ScaledBaseUnit<YardBaseUnit, 1, 3>;
-impl BaseUnitInfo for FootBaseUnit {
-    const NAME: Info = "feet";
-    const SYMBOL: Info = "ft";
+pub type FurlongBaseUnit = ScaledBaseUnit<ChainBaseUnit, 10>;
+impl BaseUnitInfo for FurlongBaseUnit {
+    const NAME: Info = "furlong";
+    const SYMBOL: Info = "fur";
+}
+
+pub type FathomBaseUnit = ScaledBaseUnit<MeterBaseUnit, 1143, 625>; // 1143/625 = 1.8288 m (6 ft)
+impl BaseUnitInfo for FathomBaseUnit {
+    const NAME: Info = "fathom";
+    const SYMBOL: Info = "ftm";
+}
+
+pub type NauticalMileBaseUnit = ScaledBaseUnit<MeterBaseUnit, 1852>;
+impl BaseUnitInfo for NauticalMileBaseUnit {
+    const NAME: Info = "nautical mile";
+    const SYMBOL: Info = "nmi";
+}
+
+pub type AstronomicalUnitBaseUnit = ScaledBaseUnit<MeterBaseUnit, 149_597_870_700>;
+impl BaseUnitInfo for AstronomicalUnitBaseUnit {
+    const NAME: Info = "astronomical unit";
+    const SYMBOL: Info = "au";
+}
+
+/// Distance light travels in one julian year (299 792 458 m/s × 31 557 600 s)
+pub type LightYearBaseUnit = ScaledBaseUnit<MeterBaseUnit, 9_460_730_472_580_800>;
+impl BaseUnitInfo for LightYearBaseUnit {
+    const NAME: Info = "light-year";
+    const SYMBOL: Info = "ly";
+}
+
+/// 648 000/π astronomical units
+pub struct ParsecBaseUnit;
+impl BaseUnitTag for ParsecBaseUnit {
+    type Dimension = LengthBaseDimension;
+    type Canonical = ConvQuot<ConvInt<96_939_420_213_600_000>, ConvPi>;
+}
+impl BaseUnitInfo for ParsecBaseUnit {
+    const NAME: Info = "parsec";
+    const SYMBOL: Info = "pc";
+}
+
+/// ħc/eV, the unit of length when c = ħ = 1 and energy is measured in electronvolts
+pub struct NaturalLengthBaseUnit;
+impl BaseUnitTag for NaturalLengthBaseUnit {
+    type Dimension = LengthBaseDimension;
+    type Canonical = ConvDecimal<1_973_269_804_593_025, -22>;
+}
+
+impl BaseUnitInfo for NaturalLengthBaseUnit {
+    const NAME: Info = "inverse electronvolt";
+    const SYMBOL: Info = "ħc/eV";
+}
+
+/// √(ħG/c³), CODATA 2018
+pub struct PlanckLengthBaseUnit;
+impl BaseUnitTag for PlanckLengthBaseUnit {
+    type Dimension = LengthBaseDimension;
+    type Canonical = ConvDecimal<1_616_255, -41>;
+}
+
+impl BaseUnitInfo for PlanckLengthBaseUnit {
+    const NAME: Info = "planck length";
+    const SYMBOL: Info = "l_P";
+}
+
+/// Bohr radius, CODATA 2018
+pub struct BohrRadiusBaseUnit;
+impl BaseUnitTag for BohrRadiusBaseUnit {
+    type Dimension = LengthBaseDimension;
+    type Canonical = ConvDecimal<529_177_210_903, -22>;
+}
+
+impl BaseUnitInfo for BohrRadiusBaseUnit {
+    const NAME: Info = "bohr";
+    const SYMBOL: Info = "a_0";
 }
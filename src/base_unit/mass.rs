@@ -7,38 +7,76 @@ use crate::{
 pub struct GramBaseUnit;
 impl BaseUnitTag for GramBaseUnit {
     type Dimension = MassBaseDimension;
+    type Canonical = ConvInt<1>;
 }
 impl BaseUnitInfo for GramBaseUnit {
     const NAME: Info = "gram";
     const SYMBOL: Info = "g";
+    const PREFIX: Option<i8> = Some(0);
 }
 
 pub type KilogramBaseUnit = ScaledBaseUnit<GramBaseUnit, 1000>;
 impl BaseUnitInfo for KilogramBaseUnit {
-    const NAME: Info = "kilo";
+    const NAME: Info = "kilogram";
     const SYMBOL: Info = "kg";
+    const PREFIX: Option<i8> = Some(3);
 }
 
-pub struct SlugBaseUnit;
-impl BaseUnitTag for SlugBaseUnit {
-    type Dimension = MassBaseDimension;
-}
-
-impl ConversionTo<GramBaseUnit> for SlugBaseUnit {
-    type Factor = ConvInt<14590>;
+/// Mass accelerated at 1 m/s² by one kilogram-force (9.80665 kg), the base
+/// mass of the technical (gravitational) metric system
+pub type HylBaseUnit = ScaledBaseUnit<KilogramBaseUnit, 196133, 20000>;
+impl BaseUnitInfo for HylBaseUnit {
+    const NAME: Info = "hyl";
+    const SYMBOL: Info = "hyl";
 }
 
-impl ConversionTo<SlugBaseUnit> for GramBaseUnit {
-    type Factor = ConvRatio<1,14590>;
+/// International avoirdupois pound, exactly 0.453 592 37 kg
+pub type PoundMassBaseUnit = ScaledBaseUnit<GramBaseUnit, 45_359_237, 100_000>;
+impl BaseUnitInfo for PoundMassBaseUnit {
+    const NAME: Info = "pound";
+    const SYMBOL: Info = "lbm";
 }
 
+/// Mass accelerated at 1 ft/s² by one pound-force, i.e. g<sub>n</sub> / (1 ft/s²) 
+/// = 9.806 65 / 0.3048 = 196 133/6096 pounds
+pub type SlugBaseUnit = ScaledBaseUnit<PoundMassBaseUnit, 196_133, 6_096>;
 impl BaseUnitInfo for SlugBaseUnit {
     const NAME: Info = "slug";
     const SYMBOL: Info = "slug";
 }
 
-pub type PoundMassBaseUnit = ScaledBaseUnit<SlugBaseUnit, 16087, 500>; // 16087/500 = 32.174
-impl BaseUnitInfo for PoundMassBaseUnit {
-    const NAME: Info = "pound";
-    const SYMBOL: Info = "lbm";
+/// Mass equivalent of one electronvolt (e/c²), the base mass of natural units
+pub struct ElectronVoltMassBaseUnit;
+impl BaseUnitTag for ElectronVoltMassBaseUnit {
+    type Dimension = MassBaseDimension;
+    type Canonical = ConvDecimal<1_782_661_921_627_898, -48>;
+}
+
+impl BaseUnitInfo for ElectronVoltMassBaseUnit {
+    const NAME: Info = "electronvolt";
+    const SYMBOL: Info = "eV";
+}
+
+/// √(ħc/G), CODATA 2018
+pub struct PlanckMassBaseUnit;
+impl BaseUnitTag for PlanckMassBaseUnit {
+    type Dimension = MassBaseDimension;
+    type Canonical = ConvDecimal<2_176_434, -11>;
+}
+
+impl BaseUnitInfo for PlanckMassBaseUnit {
+    const NAME: Info = "planck mass";
+    const SYMBOL: Info = "m_P";
+}
+
+/// Electron rest mass, CODATA 2018
+pub struct ElectronMassBaseUnit;
+impl BaseUnitTag for ElectronMassBaseUnit {
+    type Dimension = MassBaseDimension;
+    type Canonical = ConvDecimal<91_093_837_015, -38>;
+}
+
+impl BaseUnitInfo for ElectronMassBaseUnit {
+    const NAME: Info = "electron mass";
+    const SYMBOL: Info = "m_e";
 }
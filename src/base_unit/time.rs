@@ -1,4 +1,12 @@
+//! Fixed-length units of time. 
+//! 
+//! Calendar months (and Gregorian years) are deliberately not provided: their 
+//! length depends on the date, so they cannot be expressed as a constant 
+//! conversion factor. Use [`CommonYearBaseUnit`] or [`LeapYearBaseUnit`] when
+//! the length of a particular year is known.
+
 use crate::{
+    conversion::*,
     dimension::*,
     unit::*
 };
@@ -6,10 +14,91 @@ use crate::{
 pub struct SecondBaseUnit;
 impl BaseUnitTag for SecondBaseUnit {
     type Dimension = TimeBaseDimension;
+    type Canonical = ConvInt<1>;
 }
 impl BaseUnitInfo for SecondBaseUnit {
     const NAME: Info = "second";
     const SYMBOL: Info = "s";
+    const PREFIX: Option<i8> = Some(0);
 }
 
 pub type MinuteBaseUnit = ScaledBaseUnit<SecondBaseUnit, 60>;
+impl BaseUnitInfo for MinuteBaseUnit {
+    const NAME: Info = "minute";
+    const SYMBOL: Info = "min";
+}
+
+pub type HourBaseUnit = ScaledBaseUnit<MinuteBaseUnit, 60>;
+impl BaseUnitInfo for HourBaseUnit {
+    const NAME: Info = "hour";
+    const SYMBOL: Info = "h";
+}
+
+pub type DayBaseUnit = ScaledBaseUnit<HourBaseUnit, 24>;
+impl BaseUnitInfo for DayBaseUnit {
+    const NAME: Info = "day";
+    const SYMBOL: Info = "d";
+}
+
+pub type WeekBaseUnit = ScaledBaseUnit<DayBaseUnit, 7>;
+impl BaseUnitInfo for WeekBaseUnit {
+    const NAME: Info = "week";
+    const SYMBOL: Info = "wk";
+}
+
+/// 365.25 days
+pub type JulianYearBaseUnit = ScaledBaseUnit<DayBaseUnit, 1461, 4>;
+impl BaseUnitInfo for JulianYearBaseUnit {
+    const NAME: Info = "julian year";
+    const SYMBOL: Info = "a";
+}
+
+/// 365 days
+pub type CommonYearBaseUnit = ScaledBaseUnit<DayBaseUnit, 365>;
+impl BaseUnitInfo for CommonYearBaseUnit {
+    const NAME: Info = "common year";
+    const SYMBOL: Info = "a_c";
+}
+
+/// 366 days
+pub type LeapYearBaseUnit = ScaledBaseUnit<DayBaseUnit, 366>;
+impl BaseUnitInfo for LeapYearBaseUnit {
+    const NAME: Info = "leap year";
+    const SYMBOL: Info = "a_l";
+}
+
+/// ħ/eV, the unit of time when c = ħ = 1 and energy is measured in electronvolts
+pub struct NaturalTimeBaseUnit;
+impl BaseUnitTag for NaturalTimeBaseUnit {
+    type Dimension = TimeBaseDimension;
+    type Canonical = ConvDecimal<6_582_119_569_509_066, -31>;
+}
+
+impl BaseUnitInfo for NaturalTimeBaseUnit {
+    const NAME: Info = "inverse electronvolt";
+    const SYMBOL: Info = "ħ/eV";
+}
+
+/// √(ħG/c⁵), CODATA 2018
+pub struct PlanckTimeBaseUnit;
+impl BaseUnitTag for PlanckTimeBaseUnit {
+    type Dimension = TimeBaseDimension;
+    type Canonical = ConvDecimal<5_391_247, -50>;
+}
+
+impl BaseUnitInfo for PlanckTimeBaseUnit {
+    const NAME: Info = "planck time";
+    const SYMBOL: Info = "t_P";
+}
+
+/// ħ/E<sub>h</sub>, the atomic unit of time, CODATA 2018
+pub struct AtomicTimeBaseUnit;
+impl BaseUnitTag for AtomicTimeBaseUnit {
+    type Dimension = TimeBaseDimension;
+    type Canonical = ConvDecimal<24_188_843_265_857, -30>;
+}
+
+impl BaseUnitInfo for AtomicTimeBaseUnit {
+    const NAME: Info = "atomic unit of time";
+    const SYMBOL: Info = "ħ/E_h";
+}
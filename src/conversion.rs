@@ -1,4 +1,6 @@
 use std::{
+    convert::TryFrom,
+    fmt::{self, Display, Formatter},
     ops::{Mul, Div}
 };
 use typenum::*;
@@ -7,6 +9,133 @@ use typenum::*;
 pub trait Convertible: Sized {
     fn convert<C: ConversionFactor>(&self) -> Self;
 
+    /// Apply the conversion factor without allocating a new value
+    fn convert_in_place<C: ConversionFactor>(&mut self) {
+        *self = self.convert::<C>();
+    }
+}
+
+/// Convert values stored in the unit `U1` to the unit `U2`, in place
+/// ```
+/// use furlong::{convert_slice, system::{si::Meters, imperial::Feet}};
+/// 
+/// let mut readings = [1.0, 10.0, 100.0];
+/// convert_slice::<Feet, Meters>(&mut readings);
+/// approx::assert_relative_eq!(readings[..], [0.3048, 3.048, 30.48][..]);
+/// ```
+pub fn convert_slice<U1: ConversionTo<U2>, U2>(values: &mut [impl Convertible]) {
+    for value in values {
+        value.convert_in_place::<Conversion<U1, U2>>();
+    }
+}
+
+/// Parallel [`convert_slice`]
+#[cfg(feature = "rayon")]
+pub fn par_convert_slice<U1: ConversionTo<U2>, U2>(values: &mut [impl Convertible + Send]) {
+    use rayon::prelude::*;
+    values.par_iter_mut().for_each(|value| value.convert_in_place::<Conversion<U1, U2>>());
+}
+
+impl<T: Convertible> Convertible for Vec<T> {
+    fn convert<C: ConversionFactor>(&self) -> Self {
+        self.iter().map(T::convert::<C>).collect()
+    }
+
+    fn convert_in_place<C: ConversionFactor>(&mut self) {
+        for value in self.iter_mut() {
+            value.convert_in_place::<C>();
+        }
+    }
+}
+
+/// How to round the result of an exact conversion that is not a whole number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Round to the nearest integer, with ties away from zero
+    #[default]
+    Nearest,
+    /// Round toward negative infinity
+    Floor,
+    /// Round toward positive infinity
+    Ceil,
+    /// Round toward zero
+    Trunc,
+}
+
+impl Rounding {
+    fn round(self, value: f64) -> f64 {
+        match self {
+            Rounding::Nearest => value.round(),
+            Rounding::Floor => value.floor(),
+            Rounding::Ceil => value.ceil(),
+            Rounding::Trunc => value.trunc(),
+        }
+    }
+}
+
+/// An integer value that is converted exactly, using the 
+/// [`NUM`](ConversionFactor::NUM)/[`DEN`](ConversionFactor::DEN) of the factor 
+/// rather than [`REAL`](ConversionFactor::REAL). Factors that are not 
+/// [`EXACT`](ConversionFactor::EXACT) are applied in floating point. 
+/// 
+/// [`Convertible::convert`] truncates toward zero, as an `as` cast of the floating 
+/// point result would, and saturates at the bounds of `Self` if the result does not 
+/// fit. Use [`convert_rounded`](ExactConvertible::convert_rounded) to choose the rounding.
+pub trait ExactConvertible: Convertible {
+    /// Convert, rounding a fractional result with `rounding`. 
+    /// 
+    /// If the exact product overflows, the conversion falls back to floating point
+    /// (saturating at the bounds of `Self`).
+    fn convert_rounded<C: ConversionFactor>(&self, rounding: Rounding) -> Self;
+
+    /// Convert, returning `None` if the result is not a whole number or does not fit in `Self`, 
+    /// or if the factor is not [`EXACT`](ConversionFactor::EXACT)
+    fn checked_convert<C: ConversionFactor>(&self) -> Option<Self>;
+}
+
+/// `value * num / den`, rounded, along with whether the result is exact. 
+/// Returns `None` on overflow. 
+pub(crate) const fn scale_signed(value: i128, num: u128, den: u128, rounding: Rounding) -> Option<(i128, bool)> {
+    if num > i128::MAX as u128 || den > i128::MAX as u128 {
+        return None;
+    }
+    let den = den as i128;
+    let product = match value.checked_mul(num as i128) {
+        Some(p) => p,
+        None => return None,
+    };
+    let (q, r) = (product / den, product % den);
+    let q = match rounding {
+        Rounding::Trunc => q,
+        Rounding::Floor if r < 0 => q - 1,
+        Rounding::Ceil if r > 0 => q + 1,
+        Rounding::Nearest if r.abs() >= den - r.abs() => q + r.signum(),
+        _ => q,
+    };
+    Some((q, r == 0))
+}
+
+/// `value * num / den`, rounded, along with whether the result is exact. 
+/// Returns `None` on overflow. 
+pub(crate) const fn scale_unsigned(value: u128, num: u128, den: u128, rounding: Rounding) -> Option<(u128, bool)> {
+    let product = match value.checked_mul(num) {
+        Some(p) => p,
+        None => return None,
+    };
+    let (q, r) = (product / den, product % den);
+    let round_up = match rounding {
+        Rounding::Ceil => r > 0,
+        Rounding::Nearest => r >= den - r,
+        Rounding::Floor | Rounding::Trunc => false,
+    };
+    if round_up {
+        match q.checked_add(1) {
+            Some(q) => Some((q, false)),
+            None => None,
+        }
+    } else {
+        Some((q, r == 0))
+    }
 }
 
 macro_rules! impl_conv_float {
@@ -23,7 +152,101 @@ macro_rules! impl_conv_float {
     }
 }
 
-impl_conv_float!{f32, f64, u32, i32, u64, i64}
+impl_conv_float!{f32, f64}
+
+macro_rules! impl_conv_int {
+    ($scale:ident, $Wide:ty, $T:ty) => {
+        impl Convertible for $T {
+            fn convert<C: ConversionFactor>(&self) -> Self {
+                self.convert_rounded::<C>(Rounding::Trunc)
+            }
+        }
+
+        impl ExactConvertible for $T {
+            fn convert_rounded<C: ConversionFactor>(&self, rounding: Rounding) -> Self {
+                Some(C::EXACT)
+                    .filter(|&exact| exact)
+                    .and_then(|_| $scale(*self as $Wide, C::NUM, C::DEN, rounding))
+                    .and_then(|(v, _)| <$T>::try_from(v).ok())
+                    .unwrap_or_else(|| rounding.round(*self as f64 * C::REAL) as Self)
+            }
+
+            fn checked_convert<C: ConversionFactor>(&self) -> Option<Self> {
+                if !C::EXACT {
+                    return None;
+                }
+                match $scale(*self as $Wide, C::NUM, C::DEN, Rounding::Trunc) {
+                    Some((v, true)) => <$T>::try_from(v).ok(),
+                    _ => None,
+                }
+            }
+        }
+    };
+    ($scale:ident, $Wide:ty, $T:ty, $($Ts:ty),+) => {
+        impl_conv_int!{$scale, $Wide, $T}
+        impl_conv_int!{$scale, $Wide, $($Ts),+}
+    }
+}
+
+impl_conv_int!{scale_signed, i128, i8, i16, i32, i64, i128, isize}
+impl_conv_int!{scale_unsigned, u128, u8, u16, u32, u64, u128, usize}
+
+/// Ratios are scaled exactly by the factor's [`NUM`](ConversionFactor::NUM)/[`DEN`](ConversionFactor::DEN). 
+/// Factors that are not [`EXACT`](ConversionFactor::EXACT) are applied as their closest 
+/// rational approximation. 
+/// 
+/// # Panics
+/// If the numerator or denominator of the factor does not fit in `T`
+#[cfg(feature = "rational")]
+impl<T> Convertible for num_rational::Ratio<T>
+where
+    T: Clone + num_integer::Integer + num_traits::FromPrimitive
+{
+    fn convert<C: ConversionFactor>(&self) -> Self {
+        let num = T::from_u128(C::NUM).expect("conversion factor numerator overflows the ratio's integer type");
+        let den = T::from_u128(C::DEN).expect("conversion factor denominator overflows the ratio's integer type");
+        self * num_rational::Ratio::new(num, den)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl Convertible for num_bigint::BigInt {
+    fn convert<C: ConversionFactor>(&self) -> Self {
+        self.convert_rounded::<C>(Rounding::Trunc)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl ExactConvertible for num_bigint::BigInt {
+    fn convert_rounded<C: ConversionFactor>(&self, rounding: Rounding) -> Self {
+        use num_traits::Signed;
+        let den = num_bigint::BigInt::from(C::DEN);
+        let (q, r) = num_integer::Integer::div_rem(&(self * C::NUM), &den);
+        let adjust = match rounding {
+            Rounding::Trunc => 0,
+            Rounding::Floor if r.is_negative() => -1,
+            Rounding::Ceil if r.is_positive() => 1,
+            Rounding::Nearest if r.abs() * 2u8 >= den => if r.is_negative() { -1 } else { 1 },
+            _ => 0,
+        };
+        q + adjust
+    }
+
+    fn checked_convert<C: ConversionFactor>(&self) -> Option<Self> {
+        if !C::EXACT {
+            return None;
+        }
+        let (q, r) = num_integer::Integer::div_rem(&(self * C::NUM), &num_bigint::BigInt::from(C::DEN));
+        if num_traits::Zero::is_zero(&r) { Some(q) } else { None }
+    }
+}
+
+#[cfg(feature = "complex")]
+impl<T: Convertible> Convertible for num_complex::Complex<T> {
+    fn convert<C: ConversionFactor>(&self) -> Self {
+        num_complex::Complex::new(self.re.convert::<C>(), self.im.convert::<C>())
+    }
+}
 
 pub trait ConversionTo<T> {
     type Factor: ConversionFactor;
@@ -31,33 +254,236 @@ pub trait ConversionTo<T> {
 
 pub type Conversion<From, To> = <From as ConversionTo<To>>::Factor;
 
+/// The conversion factor from `From` to `To`
+/// ```
+/// use furlong::{factor, system::{si::Meters, imperial::Feet}};
+/// 
+/// let ft_to_m = factor::<Feet, Meters>();
+/// assert_eq!(ft_to_m.real(), 0.3048);
+/// assert_eq!(ft_to_m.numerator(), Some(381));
+/// assert_eq!(ft_to_m.denominator(), Some(1250));
+/// assert_eq!(ft_to_m.to_string(), "381/1250");
+/// ```
+pub const fn factor<From: ConversionTo<To>, To>() -> Factor {
+    Factor::of::<Conversion<From, To>>()
+}
+
+/// The value of a [`ConversionFactor`], see [`factor`]. 
+/// 
+/// Displays as the reduced fraction when exact, otherwise as the floating 
+/// point value (honouring the formatter's precision). 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Factor {
+    real: f64,
+    num: u128,
+    den: u128,
+    exact: bool,
+}
+
+impl Factor {
+    /// The value of the conversion factor `C`
+    pub const fn of<C: ConversionFactor>() -> Self {
+        let real = if C::EXACT { C::NUM as f64 / C::DEN as f64 } else { C::REAL };
+        Factor { real, num: C::NUM, den: C::DEN, exact: C::EXACT }
+    }
+
+    /// The factor as a floating point number. 
+    /// 
+    /// For exact factors this is computed from the reduced fraction, which can be 
+    /// closer than [`ConversionFactor::REAL`] (a product of rounded factors). 
+    pub const fn real(&self) -> f64 {
+        self.real
+    }
+
+    /// The numerator in lowest terms, if the factor is exact
+    pub const fn numerator(&self) -> Option<u128> {
+        if self.exact { Some(self.num) } else { None }
+    }
+
+    /// The denominator in lowest terms, if the factor is exact
+    pub const fn denominator(&self) -> Option<u128> {
+        if self.exact { Some(self.den) } else { None }
+    }
+
+    /// Whether the factor is exactly rational
+    pub const fn is_exact(&self) -> bool {
+        self.exact
+    }
+}
+
+impl Display for Factor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.numerator(), self.denominator()) {
+            (Some(num), Some(1)) => write!(f, "{}", num),
+            (Some(num), Some(den)) => write!(f, "{}/{}", num, den),
+            _ => match f.precision() {
+                Some(p) => write!(f, "{:.*}", p, self.real),
+                None => write!(f, "{}", self.real),
+            },
+        }
+    }
+}
+
+/// A compile-time conversion factor. 
+/// 
+/// [`NUM`](ConversionFactor::NUM) and [`DEN`](ConversionFactor::DEN) are kept 
+/// in lowest terms at every step. If a factor cannot be represented exactly 
+/// (i.e. its reduced numerator or denominator overflows `u128`), evaluating 
+/// `NUM` or `DEN` fails to compile:
+/// ```compile_fail
+/// # use furlong::conversion::*;
+/// # use typenum::consts::P4;
+/// // (10^10)^4 = 10^40 > u128::MAX
+/// let _ = <ConvPow<ConvInt<10_000_000_000>, P4> as ConversionFactor>::NUM;
+/// ```
+/// 
+/// Factors that are not rational ([`ConvPi`], [`ConvReal`], [`ConvDecimal`]s with 
+/// too many decimals), and anything composed from them, are not 
+/// [`EXACT`](ConversionFactor::EXACT). Their `NUM`/`DEN` are the closest fraction to 
+/// [`REAL`](ConversionFactor::REAL) instead, which also fails to compile if `REAL` is 
+/// below `1/u128::MAX` or above `u128::MAX`:
+/// ```
+/// # use furlong::conversion::*;
+/// type DegreesToRadians = ConvQuot<ConvPi, ConvInt<180>>;
+/// assert!(!DegreesToRadians::EXACT);
+/// assert_eq!(DegreesToRadians::REAL, std::f64::consts::PI / 180.0);
+/// ```
 pub trait ConversionFactor {
     /// Conversion factor expressed as a floating point number
     const REAL: f64;
 
     /// Numerator of this conversion factor expressed as a fraction
-    const NUM: u32;
+    const NUM: u128;
 
     /// Denomenator of this conversion factor expressed as a fraction
-    const DEN: u32;
+    const DEN: u128;
+
+    /// Whether [`NUM`](ConversionFactor::NUM)/[`DEN`](ConversionFactor::DEN) is exactly 
+    /// this conversion factor, rather than an approximation of [`REAL`](ConversionFactor::REAL)
+    const EXACT: bool = true;
+}
+
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+const fn mul_exact(a: u128, b: u128) -> u128 {
+    match a.checked_mul(b) {
+        Some(p) => p,
+        None => panic!("conversion factor cannot be represented exactly: numerator or denominator overflows u128"),
+    }
+}
+
+/// Product of two fractions in lowest terms. Common factors are cancelled 
+/// across before multiplying, so the result is also in lowest terms. 
+const fn ratio_mul((n1, d1): (u128, u128), (n2, d2): (u128, u128)) -> (u128, u128) {
+    let g1 = gcd(n1, d2);
+    let g2 = gcd(n2, d1);
+    (mul_exact(n1 / g1, n2 / g2), mul_exact(d1 / g2, d2 / g1))
+}
+
+const fn ratio_pow(mut x: (u128, u128), mut n: u32) -> (u128, u128) {
+    let mut y = (1, 1);
+    while n > 0 {
+        if n % 2 == 1 {
+            y = ratio_mul(y, x);
+        }
+        n /= 2;
+        if n > 0 {
+            x = ratio_mul(x, x);
+        }
+    }
+    y
+}
+
+/// Closest fraction to `real` (the last convergent of its continued fraction 
+/// that fits in `u128`). 
+/// 
+/// # Panics
+/// If `real` is out of the range of such fractions (`1/u128::MAX` to `u128::MAX`), 
+/// which fails const evaluation of the factor's `NUM` and `DEN`
+const fn approximate(real: f64) -> (u128, u128) {
+    if real.is_nan() || real <= 0.0 {
+        return (0, 1);
+    }
+    if real < 1.0 / u128::MAX as f64 || real >= u128::MAX as f64 {
+        panic!("conversion factor cannot be approximated: it is out of the range of u128 fractions");
+    }
+    // convergents h/k, starting from h_-1/k_-1 = 1/0 and h_-2/k_-2 = 0/1
+    let (mut h1, mut k1, mut h2, mut k2) = (1u128, 0u128, 0u128, 1u128);
+    let mut x = real;
+    loop {
+        let a = x as u128;
+        let (h, k) = match (a.checked_mul(h1), a.checked_mul(k1)) {
+            (Some(ah), Some(ak)) => match (ah.checked_add(h2), ak.checked_add(k2)) {
+                (Some(h), Some(k)) => (h, k),
+                _ => break,
+            },
+            _ => break,
+        };
+        (h2, k2, h1, k1) = (h1, k1, h, k);
+        let frac = x - a as f64;
+        if h as f64 / k as f64 == real || frac <= 0.0 {
+            break;
+        }
+        x = 1.0 / frac;
+    }
+    if k1 == 0 { (u128::MAX, 1) } else { (h1, k1) }
+}
+
+/// [`ratio_mul`] if both factors are exact, otherwise the approximation of their product
+const fn product(exact: bool, a: (u128, u128), b: (u128, u128), real: f64) -> (u128, u128) {
+    if exact { ratio_mul(a, b) } else { approximate(real) }
+}
+
+/// [`ratio_pow`] if the factor is exact, otherwise the approximation of its power
+const fn power(exact: bool, x: (u128, u128), n: u32, real: f64) -> (u128, u128) {
+    if exact { ratio_pow(x, n) } else { approximate(real) }
 }
 
-pub struct ConvInt<const I: u32 = 1>;
+/// `m * 10^e` in lowest terms, or `None` if it does not fit in `u128`
+const fn decimal(m: u64, e: i32) -> Option<(u128, u128)> {
+    let mut scale = 1u128;
+    let mut i = 0;
+    while i < e.unsigned_abs() {
+        scale = match scale.checked_mul(10) {
+            Some(s) => s,
+            None => return None,
+        };
+        i += 1;
+    }
+    if e >= 0 {
+        match (m as u128).checked_mul(scale) {
+            Some(n) => Some((n, 1)),
+            None => None,
+        }
+    } else {
+        let g = gcd(m as u128, scale);
+        Some((m as u128 / g, scale / g))
+    }
+}
+
+pub struct ConvInt<const I: u64 = 1>;
 
-impl<const I: u32> ConversionFactor for ConvInt<I> {
+impl<const I: u64> ConversionFactor for ConvInt<I> {
     const REAL: f64 = I as f64;
-    const NUM: u32 = I;
-    const DEN: u32 = 1;
+    const NUM: u128 = I as u128;
+    const DEN: u128 = 1;
 }
 
-impl<const I: u32, F: ConversionFactor> Mul<F> for ConvInt<I> {
+impl<const I: u64, F: ConversionFactor> Mul<F> for ConvInt<I> {
     type Output = ConvProd<Self, F>;
     fn mul(self, _rhs: F) -> Self::Output {
         unimplemented!()
     }
 }
 
-impl<const I: u32, F: ConversionFactor> Div<F> for ConvInt<I> {
+impl<const I: u64, F: ConversionFactor> Div<F> for ConvInt<I> {
     type Output = ConvQuot<Self, F>;
     fn div(self, _rhs: F) -> Self::Output {
         unimplemented!()
@@ -68,20 +494,21 @@ pub struct ConvRecip<C>(C);
 
 impl<C: ConversionFactor> ConversionFactor for ConvRecip<C> {
     const REAL: f64 = 1.0 / C::REAL;
-    const NUM: u32 = C::DEN;
-    const DEN: u32 = C::NUM;
+    const NUM: u128 = C::DEN;
+    const DEN: u128 = C::NUM;
+    const EXACT: bool = C::EXACT;
 }
 
-pub type ConvRatio<const N: u32, const D: u32> = ConvQuot<ConvInt<N>, ConvInt<D>>;
+pub type ConvRatio<const N: u64, const D: u64> = ConvQuot<ConvInt<N>, ConvInt<D>>;
 
-impl<const N: u32, const D: u32, F: ConversionFactor> Mul<F> for ConvRatio<N, D> {
+impl<const N: u64, const D: u64, F: ConversionFactor> Mul<F> for ConvRatio<N, D> {
     type Output = ConvProd<Self, F>;
     fn mul(self, _rhs: F) -> Self::Output {
         unimplemented!()
     }
 }
 
-impl<const N: u32, const D: u32, F: ConversionFactor> Div<F> for ConvRatio<N, D> {
+impl<const N: u64, const D: u64, F: ConversionFactor> Div<F> for ConvRatio<N, D> {
     type Output = ConvQuot<Self, F>;
     fn div(self, _rhs: F) -> Self::Output {
         unimplemented!()
@@ -92,16 +519,18 @@ pub struct ConvProd<A, B>(A, B);
 
 impl<A: ConversionFactor, B: ConversionFactor> ConversionFactor for ConvProd<A, B> {
     const REAL: f64 = A::REAL * B::REAL;
-    const NUM: u32 = A::NUM * B::NUM;
-    const DEN: u32 = A::DEN * B::DEN;
+    const NUM: u128 = product(Self::EXACT, (A::NUM, A::DEN), (B::NUM, B::DEN), Self::REAL).0;
+    const DEN: u128 = product(Self::EXACT, (A::NUM, A::DEN), (B::NUM, B::DEN), Self::REAL).1;
+    const EXACT: bool = A::EXACT && B::EXACT;
 }
 
 pub struct ConvQuot<A, B>(A, B);
 
 impl<A: ConversionFactor, B: ConversionFactor> ConversionFactor for ConvQuot<A, B> {
     const REAL: f64 = A::REAL / B::REAL;
-    const NUM: u32 = A::NUM * B::DEN;
-    const DEN: u32 = A::DEN * B::NUM;
+    const NUM: u128 = product(Self::EXACT, (A::NUM, A::DEN), (B::DEN, B::NUM), Self::REAL).0;
+    const DEN: u128 = product(Self::EXACT, (A::NUM, A::DEN), (B::DEN, B::NUM), Self::REAL).1;
+    const EXACT: bool = A::EXACT && B::EXACT;
 }
 
 pub struct ConvPow<C, N>(C, N);
@@ -140,32 +569,110 @@ macro_rules! power_n {
 
 impl<C> ConversionFactor for ConvPow<C, Z0> {
     const REAL: f64 = 1.0;
-    const NUM: u32 = 1;
-    const DEN: u32 = 1;
+    const NUM: u128 = 1;
+    const DEN: u128 = 1;
 }
 
 impl<C: ConversionFactor> ConversionFactor for ConvPow<C, P1> {
     const REAL: f64 = C::REAL;
-    const NUM: u32 = C::NUM;
-    const DEN: u32 = C::DEN;
+    const NUM: u128 = C::NUM;
+    const DEN: u128 = C::DEN;
+    const EXACT: bool = C::EXACT;
 }
 
 impl<C: ConversionFactor, U: Unsigned, B1: Bit, B2: Bit> ConversionFactor for ConvPow<C, PInt<UInt<UInt<U, B1>,B2>>> {
     const REAL: f64 = power_n!(C::REAL, <UInt<UInt<U, B1>,B2> as Unsigned>::U32);
-    const NUM: u32 = power_n!(C::NUM, <UInt<UInt<U, B1>,B2> as Unsigned>::U32, u32);
-    const DEN: u32 = power_n!(C::DEN, <UInt<UInt<U, B1>,B2> as Unsigned>::U32, u32);
+    const NUM: u128 = power(C::EXACT, (C::NUM, C::DEN), <UInt<UInt<U, B1>,B2> as Unsigned>::U32, Self::REAL).0;
+    const DEN: u128 = power(C::EXACT, (C::NUM, C::DEN), <UInt<UInt<U, B1>,B2> as Unsigned>::U32, Self::REAL).1;
+    const EXACT: bool = C::EXACT;
 }
 
 impl<C: ConversionFactor> ConversionFactor for ConvPow<C, N1> {
     const REAL: f64 = 1.0 / C::REAL;
-    const NUM: u32 = C::DEN;
-    const DEN: u32 = C::NUM;
+    const NUM: u128 = C::DEN;
+    const DEN: u128 = C::NUM;
+    const EXACT: bool = C::EXACT;
 }
 
 impl<C: ConversionFactor, U: Unsigned, B1: Bit, B2: Bit> ConversionFactor for ConvPow<C, NInt<UInt<UInt<U, B1>,B2>>> {
     const REAL: f64 = 1.0 / power_n!(C::REAL, <UInt<UInt<U, B1>,B2> as Unsigned>::U32);
-    const NUM: u32 = power_n!(C::DEN, <UInt<UInt<U, B1>,B2> as Unsigned>::U32, u32);
-    const DEN: u32 = power_n!(C::NUM, <UInt<UInt<U, B1>,B2> as Unsigned>::U32, u32);
+    const NUM: u128 = power(C::EXACT, (C::DEN, C::NUM), <UInt<UInt<U, B1>,B2> as Unsigned>::U32, Self::REAL).0;
+    const DEN: u128 = power(C::EXACT, (C::DEN, C::NUM), <UInt<UInt<U, B1>,B2> as Unsigned>::U32, Self::REAL).1;
+    const EXACT: bool = C::EXACT;
+}
+
+/// A decimal factor, `M * 10^E`. 
+/// 
+/// This is exact as long as it fits in `u128` (e.g. `ConvDecimal<45_359_237, -8>`); 
+/// longer fractional expansions are not [`EXACT`](ConversionFactor::EXACT). 
+/// Like any exact factor, a positive exponent that overflows `u128` fails const evaluation:
+/// ```compile_fail
+/// # use furlong::conversion::*;
+/// let _ = <ConvDecimal<1, 40> as ConversionFactor>::NUM;
+/// ```
+/// 
+/// The fraction approximating an inexact factor must be within `1/u128::MAX` and 
+/// `u128::MAX`:
+/// ```compile_fail
+/// # use furlong::conversion::*;
+/// let _ = <ConvDecimal<1, -39> as ConversionFactor>::NUM;
+/// ```
+pub struct ConvDecimal<const M: u64, const E: i32>;
+
+impl<const M: u64, const E: i32> ConversionFactor for ConvDecimal<M, E> {
+    const REAL: f64 = if E >= 0 {
+        M as f64 * power_n!(10.0, E.unsigned_abs())
+    } else {
+        M as f64 / power_n!(10.0, E.unsigned_abs())
+    };
+    const NUM: u128 = match decimal(M, E) {
+        Some((n, _)) => n,
+        None if E >= 0 => panic!("conversion factor cannot be represented exactly: numerator or denominator overflows u128"),
+        None => approximate(Self::REAL).0,
+    };
+    const DEN: u128 = match decimal(M, E) {
+        Some((_, d)) => d,
+        None if E >= 0 => panic!("conversion factor cannot be represented exactly: numerator or denominator overflows u128"),
+        None => approximate(Self::REAL).1,
+    };
+    const EXACT: bool = E >= 0 || decimal(M, E).is_some();
+}
+
+/// π, as a conversion factor. 
+/// 
+/// Powers of π are [`ConvPow<ConvPi, N>`](ConvPow).
+pub struct ConvPi;
+
+impl ConversionFactor for ConvPi {
+    const REAL: f64 = std::f64::consts::PI;
+    const NUM: u128 = approximate(Self::REAL).0;
+    const DEN: u128 = approximate(Self::REAL).1;
+    const EXACT: bool = false;
+}
+
+/// A conversion factor given by an `f64` constant, see [`ConvReal`]
+pub trait RealFactor {
+    const VALUE: f64;
+}
+
+/// A conversion factor defined by the `f64` constant of `R`, which is not exact
+/// ```
+/// # use furlong::conversion::*;
+/// struct Sqrt2;
+/// impl RealFactor for Sqrt2 {
+///     const VALUE: f64 = std::f64::consts::SQRT_2;
+/// }
+/// type Two = ConvProd<ConvReal<Sqrt2>, ConvReal<Sqrt2>>;
+/// approx::assert_relative_eq!(Two::REAL, 2.0);
+/// assert!(!Two::EXACT);
+/// ```
+pub struct ConvReal<R>(R);
+
+impl<R: RealFactor> ConversionFactor for ConvReal<R> {
+    const REAL: f64 = R::VALUE;
+    const NUM: u128 = approximate(R::VALUE).0;
+    const DEN: u128 = approximate(R::VALUE).1;
+    const EXACT: bool = false;
 }
 
 #[cfg(test)]
@@ -211,6 +718,206 @@ mod tests {
         assert_eq!(Cube::DEN, 64);
     }
 
+    #[test]
+    fn reduction() {
+        type Half = ConvRatio<2, 4>;
+        assert_eq!(Half::NUM, 1);
+        assert_eq!(Half::DEN, 2);
+
+        type One = ConvProd<ConvRatio<1250, 1143>, ConvRatio<1143, 1250>>;
+        assert_eq!(One::NUM, 1);
+        assert_eq!(One::DEN, 1);
+
+        type Q = ConvQuot<ConvRatio<6, 35>, ConvRatio<10, 21>>;
+        assert_eq!(Q::NUM, 9);
+        assert_eq!(Q::DEN, 25);
+
+        // 1 m^3 in yd^3 overflowed u32
+        type CubicYards = ConvPow<ConvRatio<1250, 1143>, P3>;
+        assert_eq!(CubicYards::NUM, 1_953_125_000);
+        assert_eq!(CubicYards::DEN, 1_493_271_207);
+
+        type Large = ConvPow<ConvRatio<1_000_000_000, 3>, N4>;
+        assert_eq!(Large::NUM, 81);
+        assert_eq!(Large::DEN, 1_000_000_000_000_000_000_000_000_000_000_000_000);
+    }
+
+    #[test]
+    fn inexact() {
+        const { assert!(!ConvPi::EXACT) };
+        assert_eq!((ConvPi::NUM, ConvPi::DEN), (245_850_922, 78_256_779));
+        assert_eq!(ConvPi::NUM as f64 / ConvPi::DEN as f64, std::f64::consts::PI);
+
+        type PiSquared = ConvPow<ConvPi, P2>;
+        const { assert!(!PiSquared::EXACT) };
+        approx::assert_relative_eq!(PiSquared::REAL, std::f64::consts::PI * std::f64::consts::PI);
+        approx::assert_relative_eq!(PiSquared::NUM as f64 / PiSquared::DEN as f64, PiSquared::REAL);
+
+        // composing with a decimal too long to be exact is not exact either
+        type Radians = ConvProd<ConvPi, ConvDecimal<1, -40>>;
+        const { assert!(!Radians::EXACT) };
+        approx::assert_relative_eq!(Radians::REAL, std::f64::consts::PI * 1e-40);
+
+        type Pound = ConvDecimal<45_359_237, -8>;
+        const { assert!(Pound::EXACT) };
+        assert_eq!((Pound::NUM, Pound::DEN), (45_359_237, 100_000_000));
+        assert_eq!(Pound::REAL, 0.453_592_37);
+        assert_eq!(<ConvDecimal<25, 3>>::NUM, 25_000);
+
+        type ElectronMass = ConvDecimal<91_093_837_015, -41>;
+        const { assert!(!ElectronMass::EXACT) };
+        assert_eq!(ElectronMass::REAL, 9.109_383_701_5e-31);
+        const { assert!(!<ConvProd<ElectronMass, ConvInt<1000>>>::EXACT) };
+
+        let degrees = 180_i32;
+        assert_eq!(degrees.convert::<ConvQuot<ConvPi, ConvInt<180>>>(), 3);
+        assert_eq!(degrees.convert_rounded::<ConvQuot<ConvPi, ConvInt<180>>>(Rounding::Ceil), 4);
+        assert_eq!(degrees.checked_convert::<ConvQuot<ConvPi, ConvInt<180>>>(), None);
+        assert_eq!(1_u8.convert::<ConvPow<ConvPi, P2>>(), 9);
+    }
+
+    #[test]
+    fn approximation_range() {
+        let (lowest, highest) = (1.0 / u128::MAX as f64, u128::MAX as f64);
+        assert_eq!(approximate(lowest), (1, u128::MAX));
+        assert_eq!(approximate(highest / 2.0), (1 << 127, 1));
+        // the smallest and largest decimals that can be approximated
+        assert_eq!(<ConvDecimal<3, -39>>::NUM, 1);
+        approx::assert_relative_eq!(<ConvDecimal<3, -39>>::DEN as f64, 1e39 / 3.0, max_relative = 1e-15);
+        type Large = ConvProd<ConvDecimal<1, 38>, ConvPi>;
+        assert_eq!(Large::DEN, 1);
+        approx::assert_relative_eq!(Large::NUM as f64, 1e38 * std::f64::consts::PI, max_relative = 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the range")]
+    fn approximation_too_small() {
+        approximate(1.0 / u128::MAX as f64 / 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the range")]
+    fn approximation_too_large() {
+        approximate(u128::MAX as f64);
+    }
+
+    #[test]
+    fn factor_value() {
+        use crate::system::{si, imperial};
+        let mi = factor::<imperial::Miles, si::Kilometers>();
+        assert!(mi.is_exact());
+        assert_eq!((mi.numerator(), mi.denominator()), (Some(25_146), Some(15_625)));
+        assert_eq!(mi.real(), 1.609_344);
+        assert_eq!(mi.to_string(), "25146/15625");
+        assert_eq!(factor::<si::Hours, si::Seconds>().to_string(), "3600");
+
+        let rad = Factor::of::<ConvQuot<ConvPi, ConvInt<180>>>();
+        assert!(!rad.is_exact());
+        assert_eq!(rad.numerator(), None);
+        assert_eq!(format!("{:.4}", rad), "0.0175");
+        assert_eq!(rad.to_string(), (std::f64::consts::PI / 180.0).to_string());
+    }
+
+    #[test]
+    fn slices() {
+        use crate::system::{si, imperial};
+        let mut feet: Vec<f64> = (0..1_000).map(f64::from).collect();
+        convert_slice::<imperial::Feet, si::Meters>(&mut feet);
+        approx::assert_relative_eq!(feet[10], 3.048);
+
+        let mut yards = vec![1_u32, 2, 3];
+        yards.convert_in_place::<Conversion<imperial::Yards, imperial::Feet>>();
+        assert_eq!(yards, [3, 6, 9]);
+        assert_eq!(yards.convert::<Conversion<imperial::Feet, imperial::Yards>>(), [1, 2, 3]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_slices() {
+        use crate::system::{si, imperial};
+        let mut feet: Vec<f64> = (0..100_000).map(f64::from).collect();
+        let mut expected = feet.clone();
+        par_convert_slice::<imperial::Feet, si::Meters>(&mut feet);
+        convert_slice::<imperial::Feet, si::Meters>(&mut expected);
+        assert_eq!(feet, expected);
+    }
+
+    #[test]
+    fn exact_integers() {
+        // 3 m = 9.84 ft
+        type MetersToFeet = ConvRatio<1250, 381>;
+        assert_eq!(3.convert::<MetersToFeet>(), 9);
+        assert_eq!(3.convert_rounded::<MetersToFeet>(Rounding::Nearest), 10);
+        assert_eq!(3.convert_rounded::<MetersToFeet>(Rounding::Floor), 9);
+        assert_eq!(3.convert_rounded::<MetersToFeet>(Rounding::Ceil), 10);
+        assert_eq!(3.convert_rounded::<MetersToFeet>(Rounding::Trunc), 9);
+        assert_eq!((-3).convert::<MetersToFeet>(), -9);
+        assert_eq!((-3).convert_rounded::<MetersToFeet>(Rounding::Nearest), -10);
+        assert_eq!((-3).convert_rounded::<MetersToFeet>(Rounding::Floor), -10);
+        assert_eq!((-3).convert_rounded::<MetersToFeet>(Rounding::Ceil), -9);
+        assert_eq!((-3).convert_rounded::<MetersToFeet>(Rounding::Trunc), -9);
+        assert_eq!(3u32.convert_rounded::<MetersToFeet>(Rounding::Floor), 9);
+        assert_eq!(3u32.convert_rounded::<MetersToFeet>(Rounding::Ceil), 10);
+
+        // ties away from zero
+        assert_eq!(1.convert_rounded::<ConvRatio<1, 2>>(Rounding::Nearest), 1);
+        assert_eq!((-1).convert_rounded::<ConvRatio<1, 2>>(Rounding::Nearest), -1);
+        assert_eq!(1u64.convert_rounded::<ConvRatio<1, 2>>(Rounding::Nearest), 1);
+        assert_eq!(1.convert::<ConvRatio<1, 2>>(), 0);
+
+        assert_eq!(381.checked_convert::<MetersToFeet>(), Some(1250));
+        assert_eq!(3.checked_convert::<MetersToFeet>(), None);
+        assert_eq!(3_000_000_000u32.checked_convert::<ConvInt<2>>(), None);
+
+        // not representable as f64
+        let big: i64 = 9_007_199_254_740_993;
+        assert_eq!(big.convert::<ConvRatio<1000, 1000>>(), big);
+        assert_eq!((big as u64).convert::<ConvRatio<3, 3>>(), big as u64);
+        assert_eq!((big / 1000).convert::<ConvInt<1000>>(), 9_007_199_254_740_000);
+    }
+
+    #[test]
+    fn primitives() {
+        type Yards = ConvInt<3>;
+        assert_eq!(100u8.convert::<Yards>(), 255);
+        assert_eq!(85u8.convert::<Yards>(), 255);
+        assert_eq!((-2i8).convert::<Yards>(), -6);
+        assert_eq!(2i16.convert::<Yards>(), 6);
+        assert_eq!(2u16.convert::<Yards>(), 6);
+        assert_eq!(2isize.convert::<Yards>(), 6);
+        assert_eq!(2usize.convert::<Yards>(), 6);
+        assert_eq!(i128::MAX.convert::<ConvRatio<7, 7>>(), i128::MAX);
+        assert_eq!((u128::MAX / 3).convert::<Yards>(), u128::MAX);
+        assert_eq!(u8::MAX.checked_convert::<Yards>(), None);
+    }
+
+    #[cfg(feature = "rational")]
+    #[test]
+    fn ratio() {
+        use num_rational::Ratio;
+        assert_eq!(Ratio::new(3, 1).convert::<ConvRatio<1250, 381>>(), Ratio::new(3750, 381));
+        assert_eq!(Ratio::new(1i64, 3).convert::<ConvPow<ConvRatio<1250, 1143>, P3>>(), Ratio::new(1_953_125_000, 4_479_813_621));
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn bigint() {
+        use num_bigint::BigInt;
+        let big = BigInt::from(u128::MAX) * 1000u32;
+        assert_eq!(big.convert::<ConvRatio<1, 1000>>(), BigInt::from(u128::MAX));
+        assert_eq!(BigInt::from(3).convert::<ConvRatio<1250, 381>>(), BigInt::from(9));
+        assert_eq!(BigInt::from(3).convert_rounded::<ConvRatio<1250, 381>>(Rounding::Nearest), BigInt::from(10));
+        assert_eq!(BigInt::from(-3).convert_rounded::<ConvRatio<1250, 381>>(Rounding::Ceil), BigInt::from(-9));
+        assert_eq!(BigInt::from(3).checked_convert::<ConvRatio<1250, 381>>(), None);
+    }
+
+    #[cfg(feature = "complex")]
+    #[test]
+    fn complex() {
+        use num_complex::Complex;
+        assert_eq!(Complex::new(1.0, -2.0).convert::<ConvInt<3>>(), Complex::new(3.0, -6.0));
+    }
+
     #[test]
     fn operations() {
         // Multiplication
@@ -223,7 +930,7 @@ mod tests {
         assert_eq!(PInv::REAL, 1.0 / P::REAL);
 
         // Compound multiplication
-        type ABC = ConvProd<ConvProd<ConvRatio<1,2>, ConvRatio<3,4>>,ConvRatio<5,6>>;
-        assert_eq!(ABC::REAL, 1.0 * 3.0 * 5.0 / (2.0 * 4.0 * 6.0));
+        type Abc = ConvProd<ConvProd<ConvRatio<1,2>, ConvRatio<3,4>>,ConvRatio<5,6>>;
+        assert_eq!(Abc::REAL, 1.0 * 3.0 * 5.0 / (2.0 * 4.0 * 6.0));
     }
 }
@@ -1,8 +1,8 @@
 use typenum::{
     array::{ATerm, TArr},
     consts::*,
-    marker_traits::{Bit, Unsigned},
-    operator_aliases::{Diff, Prod, Sub1},
+    marker_traits::{Bit, Integer, Unsigned},
+    operator_aliases::{Diff, Prod, Sub1, Sum},
     uint::UInt,
     tarr
 };
@@ -63,6 +63,7 @@ impl<V, A> Item<U0> for TArr<V, A> {
 /// Type Alias for the `I`'th item of list `L`.
 pub(crate) type GetItem<L, I> = <L as Item<I>>::Output;
 
+#[allow(dead_code)]
 pub(crate) trait TypeIter {
     type Next;
 }
@@ -95,6 +96,25 @@ where
 
 pub type GetDimPart<D, P> = <D as DimPart<P>>::Exponent;
 
+/// The exponents of mass, length and time of a dimension, as values
+pub trait DimensionVector {
+    const EXPONENTS: [i8; 3];
+}
+
+impl<D> DimensionVector for D
+where
+    D: DimPart<MassBaseDimension> + DimPart<LengthBaseDimension> + DimPart<TimeBaseDimension>,
+    GetDimPart<D, MassBaseDimension>: Integer,
+    GetDimPart<D, LengthBaseDimension>: Integer,
+    GetDimPart<D, TimeBaseDimension>: Integer,
+{
+    const EXPONENTS: [i8; 3] = [
+        <GetDimPart<D, MassBaseDimension> as Integer>::I8,
+        <GetDimPart<D, LengthBaseDimension> as Integer>::I8,
+        <GetDimPart<D, TimeBaseDimension> as Integer>::I8,
+    ];
+}
+
 pub trait SameDimension<D> {}
 
 /// [`SameDimension`] if each exponent is the same throughout the list
@@ -104,6 +124,42 @@ impl<DL: SameDimension<Dimensionless>> SameDimension<Dimensionless> for TArr<Z0,
 
 impl SameDimension<Dimensionless> for Dimensionless {}
 
+pub type Dimension<
+    Mass=Z0, 
+    Length=Z0, 
+    Time=Z0, 
+    // Current=Z0, 
+    // Temperature=Z0, 
+    // Light=Z0, 
+    // Amount=Z0
+> = tarr![
+    Mass, 
+    Length, 
+    Time, 
+    // Current, 
+    // Temperature, 
+    // Light, 
+    // Amount
+];
+
+pub type MassDimension = Dimension<P1, Z0, Z0>;
+
+pub type LengthDimension = Dimension<Z0, P1, Z0>;
+pub type AreaDimension = Prod<LengthDimension, P2>;
+// Written out rather than as `Prod<LengthDimension, P3>` so that coherence can
+// tell impls on volume and flow units apart
+pub type VolumeDimension = Dimension<Z0, P3, Z0>;
+
+pub type TimeDimension = Dimension<Z0, Z0, P1>;
+
+pub type VelocityDimension = Diff<LengthDimension, TimeDimension>;
+pub type FlowDimension = Dimension<Z0, P3, N1>;
+pub type AccelerationDimension = Diff<VelocityDimension, TimeDimension>;
+
+pub type ForceDimension = Sum<MassDimension, AccelerationDimension>;
+pub type EnergyDimension = Sum<ForceDimension, LengthDimension>;
+pub type PowerDimension = Dimension<P1, P2, N3>;
+
 #[cfg(test)]
 mod dim_list {
     use super::*;
@@ -150,31 +206,3 @@ mod dim_list {
         assert_same_dimension::<tarr![P1, P2], tarr![P1, P2, Z0, Z0]>(PhantomData, PhantomData);
     }
 }
-
-pub type Dimension<
-    Mass=Z0, 
-    Length=Z0, 
-    Time=Z0, 
-    // Current=Z0, 
-    // Temperature=Z0, 
-    // Light=Z0, 
-    // Amount=Z0
-> = tarr![
-    Mass, 
-    Length, 
-    Time, 
-    // Current, 
-    // Temperature, 
-    // Light, 
-    // Amount
-];
-
-pub type MassDimension = Dimension<P1, Z0, Z0>;
-
-pub type LengthDimension = Dimension<Z0, P1, Z0>;
-pub type AreaDimension = Prod<LengthDimension, P2>;
-pub type VolumeDimension = Prod<LengthDimension, P3>;
-
-pub type TimeDimension = Dimension<Z0, Z0, P1>;
-
-pub type VelocityDimension = Diff<LengthDimension, TimeDimension>;
@@ -0,0 +1,722 @@
+//! Formatting of unit names and symbols in different styles.
+//!
+//! ```
+//! use furlong::{format::{UnitFormat, UnitStyle, Grouping}, system::{Energy, si::System as SI}, unit::UnitInfo};
+//!
+//! type Joules = Energy<SI>;
+//! assert_eq!(Joules::format(UnitFormat::new(UnitStyle::Ascii)), "kg*m^2/s^2");
+//! assert_eq!(Joules::format(UnitFormat::new(UnitStyle::Unicode).grouping(Grouping::Exponent)), "kg·m²·s⁻²");
+//! assert_eq!(Joules::format(UnitFormat::new(UnitStyle::Latex)), r"\kilo\gram\metre\squared\per\second\squared");
+//! assert_eq!(Joules::format(UnitFormat::new(UnitStyle::Name)), "kilogram square meter per second squared");
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+use std::marker::PhantomData as PD;
+
+use crate::{
+    conversion::*,
+    locale::{English, Locale},
+    qnty::Qnty,
+    unit::*,
+};
+
+/// How units are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitStyle {
+    /// ASCII symbols with explicit separators (`kg*m^2/s^2`)
+    #[default]
+    Ascii,
+    /// Symbols with a middle dot and superscript powers (`kg·m²/s²`)
+    Unicode,
+    /// siunitx macros (`\kilo\gram\metre\squared\per\second\squared`).
+    ///
+    /// Units that siunitx does not define (e.g. feet) are written as their
+    /// symbol in `\text{...}`.
+    Latex,
+    /// Full names (`kilogram square meter per second squared`)
+    Name,
+}
+
+/// How units with negative powers are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Grouping {
+    /// Negative powers are written as a denominator (`m/s^2`)
+    #[default]
+    Fraction,
+    /// Negative powers are written as negative exponents (`m*s^-2`)
+    Exponent,
+}
+
+/// A [`UnitStyle`] and [`Grouping`] to write units with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UnitFormat {
+    style: UnitStyle,
+    grouping: Grouping,
+    group_digits: bool,
+}
+
+/// A named unit raised to a power, e.g. `s^-2`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnitPart {
+    pub name: Info,
+    pub symbol: Info,
+    pub power: i8,
+    /// See [`BaseUnitInfo::PREFIX`]
+    pub prefix: Option<i8>,
+    /// See [`BaseUnitInfo::PLURAL`]
+    pub plural: Option<Info>,
+}
+
+impl UnitFormat {
+    pub const fn new(style: UnitStyle) -> Self {
+        UnitFormat { style, grouping: Grouping::Fraction, group_digits: false }
+    }
+
+    pub const fn grouping(self, grouping: Grouping) -> Self {
+        UnitFormat { grouping, ..self }
+    }
+
+    /// Whether [`QntyDisplay`] separates the integer digits of the number in groups
+    /// of three, with the [`group_separator`](Locale::group_separator) of its locale.
+    /// This is unrelated to the [`Grouping`] of the unit.
+    pub const fn group_digits(self, group_digits: bool) -> Self {
+        UnitFormat { group_digits, ..self }
+    }
+
+    pub const fn style(&self) -> UnitStyle {
+        self.style
+    }
+
+    /// Write `parts` (in order) in this format
+    pub fn write(&self, parts: &[UnitPart]) -> String {
+        self.write_in(parts, &English, false)
+    }
+
+    /// Write `parts` (in order) in this format, with names from `locale`. If `plural`,
+    /// the last unit before the denominator takes the plural (e.g. "meters per second").
+    pub fn write_in(&self, parts: &[UnitPart], locale: &dyn Locale, plural: bool) -> String {
+        let (num, den): (Vec<_>, Vec<_>) = parts.iter()
+            .filter(|part| part.power != 0)
+            .copied()
+            .partition(|part| part.power > 0 || self.grouping == Grouping::Exponent);
+        let den: Vec<_> = den.into_iter().map(|part| UnitPart { power: -part.power, ..part }).collect();
+
+        match self.style {
+            UnitStyle::Ascii => self.symbols(&num, &den, "*", |power| format!("^{}", power)),
+            UnitStyle::Unicode => self.symbols(&num, &den, "·", |power| superscript(&power.to_string())),
+            UnitStyle::Latex => {
+                let mut out: String = num.iter().map(|part| latex(part, part.power)).collect();
+                for part in &den {
+                    out.push_str(r"\per");
+                    out.push_str(&latex(part, part.power));
+                }
+                out
+            },
+            UnitStyle::Name => {
+                let mut words: Vec<String> = num.iter().enumerate()
+                    .map(|(i, part)| locale.unit_name(part, plural && i + 1 == num.len(), false))
+                    .collect();
+                words.extend(den.iter().map(|part| format!("{} {}", locale.per(), locale.unit_name(part, false, true))));
+                words.join(" ")
+            },
+        }
+    }
+
+    fn symbols(&self, num: &[UnitPart], den: &[UnitPart], sep: &str, power: impl Fn(i8) -> String) -> String {
+        let join = |parts: &[UnitPart]| parts.iter()
+            .map(|part| {
+                let symbol = match self.style {
+                    UnitStyle::Unicode => unicode_symbol(part.symbol),
+                    _ => part.symbol.to_string(),
+                };
+                if part.power == 1 { symbol } else { format!("{}{}", symbol, power(part.power)) }
+            })
+            .collect::<Vec<_>>()
+            .join(sep);
+        match (num.len(), den.len()) {
+            (_, 0) => join(num),
+            (0, _) => format!("1/{}", group(join(den), den.len())),
+            _ => format!("{}/{}", join(num), group(join(den), den.len())),
+        }
+    }
+}
+
+fn group(den: String, len: usize) -> String {
+    if len > 1 { format!("({})", den) } else { den }
+}
+
+fn superscript(s: &str) -> String {
+    s.chars().map(|c| match c {
+        '-' => '⁻',
+        '0' => '⁰', '1' => '¹', '2' => '²', '3' => '³', '4' => '⁴',
+        '5' => '⁵', '6' => '⁶', '7' => '⁷', '8' => '⁸', '9' => '⁹',
+        c => c,
+    }).collect()
+}
+
+/// Symbols of named units may contain ASCII powers and separators (e.g. `m^3/s`)
+fn unicode_symbol(symbol: &str) -> String {
+    let mut out = String::new();
+    let mut chars = symbol.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '^' => {
+                while let Some(&d) = chars.peek().filter(|d| d.is_ascii_digit() || **d == '-') {
+                    out.push_str(&superscript(&d.to_string()));
+                    chars.next();
+                }
+            },
+            '*' => out.push('·'),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// siunitx macros of the units it defines, by English name
+const SIUNITX: &[(&str, &str)] = &[
+    ("gram", "gram"), ("meter", "metre"), ("second", "second"), ("minute", "minute"),
+    ("hour", "hour"), ("day", "day"), ("liter", "litre"), ("tonne", "tonne"),
+    ("hectare", "hectare"), ("newton", "newton"), ("joule", "joule"), ("watt", "watt"),
+    ("pascal", "pascal"), ("electronvolt", "electronvolt"), ("astronomical unit", "astronomicalunit"),
+];
+
+/// The prefix and unit macros of `part` (e.g. `\kilo\gram`), or its symbol 
+/// as `\text{...}` if siunitx does not define the unit
+fn latex(part: &UnitPart, power: i8) -> String {
+    let prefix = part.prefix.and_then(Prefix::from_exponent);
+    let name = prefix.and_then(|prefix| part.name.strip_prefix(prefix.name)).unwrap_or(part.name);
+    let unit = match SIUNITX.iter().find(|(english, _)| *english == name) {
+        Some((_, unit)) => match prefix.filter(|prefix| prefix.exponent != 0) {
+            Some(prefix) => format!(r"\{}\{}", prefix.name, unit),
+            None => format!(r"\{}", unit),
+        },
+        None => format!(r"\text{{{}}}", part.symbol),
+    };
+    match power {
+        1 => unit,
+        2 => format!(r"{}\squared", unit),
+        3 => format!(r"{}\cubed", unit),
+        n => format!(r"{}\tothe{{{}}}", unit, n),
+    }
+}
+
+/// An SI prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Prefix {
+    pub name: Info,
+    pub symbol: Info,
+    /// Power of ten this prefix multiplies by
+    pub exponent: i8,
+}
+
+macro_rules! prefixes {
+    ($($name:literal $symbol:literal $exponent:literal),+ $(,)?) => {
+        &[$(Prefix { name: $name, symbol: $symbol, exponent: $exponent }),+]
+    };
+}
+
+/// The SI prefixes, from quecto to quetta
+pub const PREFIXES: &[Prefix] = prefixes![
+    "quecto" "q" -30, "ronto" "r" -27, "yocto" "y" -24, "zepto" "z" -21, "atto" "a" -18,
+    "femto" "f" -15, "pico" "p" -12, "nano" "n" -9, "micro" "µ" -6, "milli" "m" -3,
+    "centi" "c" -2, "deci" "d" -1, "" "" 0, "deca" "da" 1, "hecto" "h" 2,
+    "kilo" "k" 3, "mega" "M" 6, "giga" "G" 9, "tera" "T" 12, "peta" "P" 15,
+    "exa" "E" 18, "zetta" "Z" 21, "yotta" "Y" 24, "ronna" "R" 27, "quetta" "Q" 30,
+];
+
+impl Prefix {
+    /// The prefix for `10^exponent`, if there is one
+    pub fn from_exponent(exponent: i8) -> Option<&'static Prefix> {
+        PREFIXES.iter().find(|prefix| prefix.exponent == exponent)
+    }
+}
+
+/// Displays a [`Qnty`] with the engineering prefix that puts its value in [1, 1000), 
+/// see [`Qnty::display_engineering`]
+pub struct EngineeringDisplay<'a, U, T> {
+    qnty: &'a Qnty<U, T>,
+}
+
+impl<U, T> Qnty<U, T> {
+    /// Display this quantity with the SI prefix (a multiple of 3) that puts its value 
+    /// in [1, 1000). Without a precision, up to 3 decimals are shown. 
+    /// 
+    /// Units that do not take SI prefixes (e.g. hours or feet), or that are not a
+    /// single unit to the first power, are displayed in their own unit.
+    /// ```
+    /// # use furlong::system::{si::{Meters, Watts, Hours}, imperial::Feet};
+    /// assert_eq!(Meters::new(0.000_42).display_engineering().to_string(), "420 µm");
+    /// assert_eq!(Watts::new(1.2e7).display_engineering().to_string(), "12 MW");
+    /// assert_eq!(format!("{:.2}", Watts::new(1.2e7).display_engineering()), "12.00 MW");
+    /// assert_eq!(Hours::new(1_500.0).display_engineering().to_string(), "1500 h");
+    /// assert_eq!(Feet::new(0.001).display_engineering().to_string(), "0.001 ft");
+    /// ```
+    pub fn display_engineering(&self) -> EngineeringDisplay<'_, U, T> {
+        EngineeringDisplay { qnty: self }
+    }
+}
+
+impl<U, T> Display for EngineeringDisplay<'_, U, T>
+where
+    U: UnitInfo,
+    GetSystemUnit<U>: ConversionTo<U>,
+    T: Convertible + num_traits::ToPrimitive,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = self.qnty.value().to_f64().unwrap_or(f64::NAN);
+        let parts: Vec<_> = U::parts().into_iter().filter(|part| part.power != 0).collect();
+        let prefixed = match parts.as_slice() {
+            [part @ UnitPart { power: 1, prefix: Some(own), .. }] if value.is_finite() && value != 0.0 => 
+            // units that give a prefix SI does not have are written without one
+            Prefix::from_exponent(*own).map(|own| {
+                // value and symbol without the unit's own prefix
+                let symbol = part.symbol.strip_prefix(own.symbol).unwrap_or(part.symbol);
+                let value = value * 10f64.powi(own.exponent.into());
+                let mut exponent = ((value.abs().log10() / 3.0).floor() * 3.0).clamp(-30.0, 30.0) as i8;
+                // rounding can carry the mantissa up to 1000
+                if mantissa(value / 10f64.powi(exponent.into()), f.precision()).abs() >= 1000.0 && exponent < 30 {
+                    exponent += 3;
+                }
+                let prefix = Prefix::from_exponent(exponent).expect("multiples of 3 are SI prefixes");
+                (value / 10f64.powi(exponent.into()), format!("{}{}", prefix.symbol, symbol))
+            }),
+            _ => None,
+        };
+        let (value, unit) = prefixed.unwrap_or_else(|| (value, U::format(UnitFormat::default())));
+        let value = match f.precision() {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => {
+                let value = format!("{:.3}", value);
+                value.trim_end_matches('0').trim_end_matches('.').to_string()
+            }
+        };
+        pad(f, &format!("{} {}", value, unit))
+    }
+}
+
+/// `value` rounded as it will be displayed
+fn mantissa(value: f64, precision: Option<usize>) -> f64 {
+    let scale = 10f64.powi(precision.unwrap_or(3) as i32);
+    (value * scale).round() / scale
+}
+
+/// Significant figures of an `f64`: more digits are not meaningful
+const MAX_SIG_FIGS: usize = 17;
+
+/// `value` rounded to `digits` significant figures (at most [`MAX_SIG_FIGS`])
+pub(crate) fn round_sig(value: f64, digits: usize) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let shift = digits.clamp(1, MAX_SIG_FIGS) as i32 - 1 - value.abs().log10().floor() as i32;
+    // dividing by an exact power of ten is more accurate than multiplying by its inverse
+    let rounded = if shift >= 0 {
+        (value * 10f64.powi(shift)).round() / 10f64.powi(shift)
+    } else {
+        (value / 10f64.powi(-shift)).round() * 10f64.powi(-shift)
+    };
+    // the power of ten overflows for subnormal values, which are left as they are
+    if rounded.is_finite() { rounded } else { value }
+}
+
+/// `value` written with `digits` significant figures (at most [`MAX_SIG_FIGS`]), 
+/// e.g. "0.00120" or "1230"
+pub(crate) fn sig_figs(value: f64, digits: usize) -> String {
+    let digits = digits.clamp(1, MAX_SIG_FIGS);
+    let value = round_sig(value, digits);
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let magnitude = if value == 0.0 { 0 } else { value.abs().log10().floor() as i32 };
+    let decimals = (digits as i32 - 1 - magnitude).max(0) as usize;
+    format!("{:.*}", decimals, value)
+}
+
+/// Displays a [`Qnty`] with a number of significant figures, see [`Qnty::to_sig_figs`]
+pub struct SigFigsDisplay<'a, U, T> {
+    qnty: &'a Qnty<U, T>,
+    digits: usize,
+}
+
+impl<U, T> Qnty<U, T> {
+    /// Display this quantity in its own unit with `digits` significant figures, 
+    /// whatever its magnitude. An `f64` has at most 17 significant figures, so larger
+    /// `digits` are treated as 17.
+    /// ```
+    /// # use furlong::system::si::{Meters, Kilometers};
+    /// assert_eq!(Meters::new(1_234.5).to_sig_figs(3).to_string(), "1230 m");
+    /// assert_eq!(Kilometers::new(0.001_234_5).to_sig_figs(3).to_string(), "0.00123 km");
+    /// assert_eq!(Meters::new(0.5).to_sig_figs(2).to_string(), "0.50 m");
+    /// ```
+    pub fn to_sig_figs(&self, digits: usize) -> SigFigsDisplay<'_, U, T> {
+        SigFigsDisplay { qnty: self, digits }
+    }
+
+    /// Round this quantity to `digits` significant figures of its value in its own unit
+    /// ```
+    /// # use furlong::system::si::Kilometers;
+    /// let d = Kilometers::new(1.234_5_f64).round_sig(2);
+    /// assert!((d.value() - 1.2).abs() < 1e-12);
+    /// ```
+    pub fn round_sig(&self, digits: usize) -> Self
+    where
+        U: Unit + ConversionTo<GetSystemUnit<U>>,
+        GetSystemUnit<U>: ConversionTo<U>,
+        T: Convertible + num_traits::Float,
+    {
+        let value = self.value().to_f64().map_or(self.value(), |value| T::from(round_sig(value, digits)).expect("a float"));
+        Qnty::from_raw_value(value.convert::<Conversion<U, GetSystemUnit<U>>>())
+    }
+}
+
+impl<U, T> Display for SigFigsDisplay<'_, U, T>
+where
+    U: UnitInfo,
+    GetSystemUnit<U>: ConversionTo<U>,
+    T: Convertible + num_traits::ToPrimitive,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let value = self.qnty.value().to_f64().unwrap_or(f64::NAN);
+        pad(f, &format!("{} {}", sig_figs(value, self.digits), U::format(UnitFormat::default())))
+    }
+}
+
+/// Write `text` (a quantity) padded to the formatter's width, right-aligned by 
+/// default like numbers. The `0` flag pads with zeros after the sign instead. 
+pub(crate) fn pad(f: &mut Formatter<'_>, text: &str) -> fmt::Result {
+    let len = text.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > len => width - len,
+        _ => return f.write_str(text),
+    };
+    if f.sign_aware_zero_pad() {
+        let (sign, rest) = text.split_at(if text.starts_with(['+', '-']) { 1 } else { 0 });
+        return write!(f, "{}{}{}", sign, "0".repeat(padding), rest);
+    }
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = |n| f.fill().to_string().repeat(n);
+    write!(f, "{}{}{}", fill(before), text, fill(after))
+}
+
+/// An ordered list of units of the same dimension, largest first, that a quantity 
+/// in `U` can be split into (e.g. `(Feet, Inches)`), see [`Qnty::display_compound`]
+pub trait CompoundUnit<U> {
+    /// Symbol of each unit, and how many of the last unit are in 1 of it
+    fn units() -> Vec<(String, f64)>;
+
+    /// Conversion from the system base unit of `U` to the last unit
+    fn last_factor() -> f64;
+}
+
+macro_rules! impl_compound_unit {
+    ($($A:ident),+ ; $L:ident) => {
+        impl<U: Unit, $($A,)+ $L> CompoundUnit<U> for ($($A,)+ $L)
+        where
+            $($A: UnitInfo + ConversionTo<$L>,)+
+            $L: UnitInfo,
+            GetSystemUnit<U>: ConversionTo<$L>,
+        {
+            fn units() -> Vec<(String, f64)> {
+                vec![$(($A::abbr(), Conversion::<$A, $L>::REAL),)+ ($L::abbr(), 1.0)]
+            }
+
+            fn last_factor() -> f64 {
+                Conversion::<GetSystemUnit<U>, $L>::REAL
+            }
+        }
+    };
+}
+
+impl_compound_unit!{A; B}
+impl_compound_unit!{A, B; C}
+impl_compound_unit!{A, B, C; D}
+
+/// Displays a [`Qnty`] split across several units, see [`Qnty::display_compound`]
+pub struct CompoundDisplay<'a, U, T, L> {
+    qnty: &'a Qnty<U, T>,
+    units: PD<L>,
+}
+
+impl<U, T> Qnty<U, T> {
+    /// Display this quantity as whole numbers of each unit in `L` but the last, 
+    /// which holds the remainder. The remainder is rounded to the formatter's 
+    /// precision (0 by default), carrying into the larger units, and leading 
+    /// units that are zero are left out. 
+    /// ```
+    /// # use furlong::system::{si::{Hours, Minutes, Seconds}, imperial::{Feet, Inches}};
+    /// assert_eq!(Feet::new(5.25).display_compound::<(Feet, Inches)>().to_string(), "5 ft 3 in");
+    /// assert_eq!(Seconds::new(4_984.0).display_compound::<(Hours, Minutes, Seconds)>().to_string(), "1 h 23 min 4 s");
+    /// assert_eq!(format!("{:.1}", Inches::new(11.96).display_compound::<(Feet, Inches)>()), "1 ft 0.0 in");
+    /// ```
+    pub fn display_compound<L: CompoundUnit<U>>(&self) -> CompoundDisplay<'_, U, T, L> {
+        CompoundDisplay { qnty: self, units: PD }
+    }
+}
+
+impl<U, T, L> Display for CompoundDisplay<'_, U, T, L>
+where
+    L: CompoundUnit<U>,
+    T: num_traits::ToPrimitive,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let step = 10f64.powi(precision as i32);
+        let round = |value: f64| (value * step).round() / step;
+
+        let value = self.qnty.raw_value().to_f64().unwrap_or(f64::NAN) * L::last_factor();
+        let units = L::units();
+        if !value.is_finite() {
+            // NaN and infinity cannot be split, so they are shown in the largest unit alone
+            return pad(f, &format!("{} {}", value, units[0].0));
+        }
+        // remainder in the last unit, rounded first so that it carries
+        let mut rest = round(value.abs());
+        let (last, leading) = units.split_last().expect("compound units are not empty");
+        let mut parts = Vec::with_capacity(units.len());
+        for (symbol, ratio) in leading {
+            let count = rest / ratio;
+            let whole = if (count - count.round()).abs() < 1e-9 { count.round() } else { count.floor() };
+            rest = round(rest - whole * ratio).max(0.0);
+            if whole != 0.0 || !parts.is_empty() {
+                parts.push(format!("{} {}", whole, symbol));
+            }
+        }
+        parts.push(format!("{:.*} {}", precision, rest, last.0));
+        let sign = if value < 0.0 && round(value) != 0.0 { "-" } else { "" };
+        pad(f, &format!("{}{}", sign, parts.join(" ")))
+    }
+}
+
+/// Displays a [`Qnty`] in its own unit, written in a [`UnitFormat`], see [`Qnty::display_with`]
+pub struct QntyDisplay<'a, U, T> {
+    qnty: &'a Qnty<U, T>,
+    format: UnitFormat,
+    locale: &'a dyn Locale,
+}
+
+impl<'a, U, T> QntyDisplay<'a, U, T> {
+    /// Write the number and unit names in `locale` instead of [`English`]
+    pub fn locale(self, locale: &'a dyn Locale) -> Self {
+        QntyDisplay { locale, ..self }
+    }
+}
+
+impl<U, T> Qnty<U, T> {
+    /// Display this quantity with its unit written in `format`, in [`English`]
+    /// unless another [`locale`](QntyDisplay::locale) is given
+    /// ```
+    /// # use furlong::{format::{UnitFormat, UnitStyle}, system::imperial::FeetPerSecond};
+    /// let v = FeetPerSecond::new(3.0);
+    /// assert_eq!(v.display_with(UnitFormat::new(UnitStyle::Name)).to_string(), "3.00 feet per second");
+    /// ```
+    pub fn display_with(&self, format: UnitFormat) -> QntyDisplay<'_, U, T> {
+        QntyDisplay { qnty: self, format, locale: &English }
+    }
+}
+
+impl<U, T> Display for QntyDisplay<'_, U, T>
+where
+    U: UnitInfo,
+    GetSystemUnit<U>: ConversionTo<U>,
+    T: Convertible + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let number = format!("{0:.1$}", self.qnty.value(), f.precision().unwrap_or(2));
+        let plural = number.parse().map_or(true, |value| self.locale.is_plural(value));
+        let parts = U::parts();
+        let unit = if parts.is_empty() { U::abbr() } else { self.format.write_in(&parts, self.locale, plural) };
+        pad(f, &format!("{} {}", self.locale.number(&number, self.format.group_digits), unit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::{
+        Acceleration, Area, Flow, Velocity,
+        si::{System as SI, LitersPerSecond, Kilometers, Watts},
+        imperial::{System as Imp, CubicFeetPerSecond},
+    };
+    use crate::unit::SystemUnit;
+    use crate::dimension::Dimension;
+    use typenum::consts::*;
+
+    #[test]
+    fn styles() {
+        type PerSecond = SystemUnit<SI, Dimension<Z0, Z0, N1>>;
+        type Odd = SystemUnit<SI, Dimension<P1, N1, N2>>;
+        let ascii = UnitFormat::new(UnitStyle::Ascii);
+        let unicode = UnitFormat::new(UnitStyle::Unicode);
+        let latex = UnitFormat::new(UnitStyle::Latex);
+        let name = UnitFormat::new(UnitStyle::Name);
+
+        assert_eq!(Velocity::<SI>::format(ascii), "m/s");
+        assert_eq!(Acceleration::<Imp>::format(ascii.grouping(Grouping::Exponent)), "ft*s^-2");
+        assert_eq!(PerSecond::format(ascii), "1/s");
+        assert_eq!(Odd::format(ascii), "kg/(m*s^2)");
+        assert_eq!(Odd::format(unicode), "kg/(m·s²)");
+        assert_eq!(Flow::<Imp>::format(unicode), "ft³/s");
+        assert_eq!(Area::<SI>::format(latex), r"\metre\squared");
+        assert_eq!(Flow::<Imp>::format(latex), r"\text{ft}\cubed\per\second");
+        assert_eq!(Odd::format(latex.grouping(Grouping::Exponent)), r"\kilo\gram\metre\tothe{-1}\second\tothe{-2}");
+        assert_eq!(PerSecond::format(name), "per second");
+        assert_eq!(Flow::<SI>::format(name), "cubic meter per second");
+        assert_eq!(Odd::format(name.grouping(Grouping::Exponent)), "kilogram meter to the power -1 second to the power -2");
+
+        // named units keep their own symbol
+        assert_eq!(Kilometers::format(latex), r"\kilo\metre");
+        assert_eq!(LitersPerSecond::format(latex), r"\text{L/s}");
+        assert_eq!(LitersPerSecond::format(ascii), "L/s");
+        assert_eq!(LitersPerSecond::format(name), "liter per second");
+        assert_eq!(CubicFeetPerSecond::format(unicode), "ft³/s");
+        assert_eq!(crate::system::si::CubicMetersPerSecond::format(name), "cubic meter per second");
+    }
+
+    #[test]
+    fn abbr_only() {
+        // a unit that only implements `abbr` is written with it in every style
+        struct Smoot;
+        impl crate::unit::Unit for Smoot {
+            type System = SI;
+            type Dim = crate::dimension::LengthDimension;
+        }
+        impl UnitInfo for Smoot {
+            fn abbr() -> String {
+                String::from("smoot")
+            }
+        }
+        assert_eq!(Smoot::format(UnitFormat::new(UnitStyle::Latex)), "smoot");
+        assert_eq!(Smoot::format(UnitFormat::new(UnitStyle::Name)), "smoot");
+    }
+
+    #[test]
+    fn engineering() {
+        use crate::system::si::{Meters, Centimeters, Watts, MillimetersPerHour};
+        use crate::system::Mass;
+        assert_eq!(Mass::<SI>::new(0.000_42).display_engineering().to_string(), "420 mg");
+        assert_eq!(Mass::<SI>::new(2_500.0).display_engineering().to_string(), "2.5 Mg");
+        assert_eq!(Kilometers::new(0.5).display_engineering().to_string(), "500 m");
+        assert_eq!(Centimeters::new(250.0).display_engineering().to_string(), "2.5 m");
+        assert_eq!(Meters::new(-0.012).display_engineering().to_string(), "-12 mm");
+        assert_eq!(Meters::new(999.999_6).display_engineering().to_string(), "1 km");
+        assert_eq!(format!("{:.1}", Meters::new(999.96).display_engineering()), "1.0 km");
+        assert_eq!(format!("{:.2}", Meters::new(999.96).display_engineering()), "999.96 m");
+        assert_eq!(Meters::new(0.0).display_engineering().to_string(), "0 m");
+        assert_eq!(Watts::new(3_u32).display_engineering().to_string(), "3 W");
+        assert_eq!(Watts::new(1e40).display_engineering().to_string(), "10000000000 QW");
+        assert_eq!(MillimetersPerHour::new(2_000.0).display_engineering().to_string(), "2000 mm/hr");
+        assert_eq!(Velocity::<SI>::new(2_000.0).display_engineering().to_string(), "2000 m/s");
+
+        // 10^4 is not an SI prefix, so the unit is kept as is
+        type Myriameters = crate::unit::ScaledUnit<Meters, 10_000>;
+        impl crate::unit::ScaledUnitInfo for Myriameters {
+            const NAME: Info = "myriameter";
+            const SYMBOL: Info = "mym";
+            const PREFIX: Option<i8> = Some(4);
+        }
+        assert_eq!(Myriameters::new(2.5).display_engineering().to_string(), "2.5 mym");
+    }
+
+    #[test]
+    fn significant_figures() {
+        use crate::system::si::Meters;
+        assert_eq!(sig_figs(1_234.5, 3), "1230");
+        assert_eq!(sig_figs(0.001_234_5, 3), "0.00123");
+        assert_eq!(sig_figs(0.001_2, 3), "0.00120");
+        assert_eq!(sig_figs(-9.996, 3), "-10.0");
+        assert_eq!(sig_figs(999.6, 3), "1000");
+        assert_eq!(sig_figs(0.0, 3), "0.00");
+        assert_eq!(sig_figs(5.0, 0), "5");
+        assert_eq!(sig_figs(f64::INFINITY, 3), "inf");
+        assert_eq!(sig_figs(123_456_789.0, 2), "120000000");
+        assert_eq!(round_sig(1_234.5, 2), 1_200.0);
+        assert_eq!(round_sig(0.012_35, 2), 0.012);
+
+        assert_eq!(Kilometers::new(12.345).to_sig_figs(2).to_string(), "12 km");
+        assert_eq!(Watts::new(1_500_u32).to_sig_figs(1).to_string(), "2000 W");
+        assert_eq!(Kilometers::new(0.012_345).to_sig_figs(3).to_string(), "0.0123 km");
+        assert_eq!(Meters::new(12_345.0).to_sig_figs(3).to_string(), "12300 m");
+        // the precision of `Display` is the number of decimals
+        assert_eq!(format!("{:.3}", Meters::new(12_345.0)), "12345.000 m");
+
+        // more digits than an f64 holds, and subnormal values
+        assert_eq!(sig_figs(0.1, 400), "0.10000000000000001");
+        assert_eq!(round_sig(0.1, 400), 0.1);
+        assert_eq!(round_sig(5e-324, 3), 5e-324);
+        assert!(sig_figs(5e-324, 3).ends_with("494"));
+        assert_eq!(round_sig(f64::MAX, 400), f64::MAX);
+        assert_eq!(Meters::new(1e-310).round_sig(2).value(), 1e-310);
+        approx::assert_relative_eq!(Kilometers::new(0.012_345).round_sig(3).value(), 0.012_3);
+        approx::assert_relative_eq!(*Meters::new(-987.6).round_sig(1).raw_value(), -1_000.0);
+    }
+
+    #[test]
+    fn compound() {
+        use crate::system::{si, imperial::{Feet, Inches, Yards, Miles, Hours, Minutes, Seconds}};
+        let ft_in = |ft: f64| Feet::new(ft).display_compound::<(Feet, Inches)>().to_string();
+        assert_eq!(ft_in(5.25), "5 ft 3 in");
+        assert_eq!(ft_in(0.25), "3 in");
+        assert_eq!(ft_in(6.0), "6 ft 0 in");
+        // 5 ft 11.96 in rounds up to 6 ft, not 5 ft 12 in
+        assert_eq!(ft_in(5.0 + 11.96 / 12.0), "6 ft 0 in");
+        assert_eq!(ft_in(-5.25), "-5 ft 3 in");
+        assert_eq!(ft_in(-0.001), "0 in");
+        assert_eq!(si::Meters::new(1.0).display_compound::<(Feet, Inches)>().to_string(), "3 ft 3 in");
+        assert_eq!(format!("{:.2}", si::Meters::new(1.0).display_compound::<(Feet, Inches)>()), "3 ft 3.37 in");
+
+        let hms = |s: f64| Seconds::new(s).display_compound::<(Hours, Minutes, Seconds)>().to_string();
+        assert_eq!(hms(4_984.0), "1 h 23 min 4 s");
+        assert_eq!(hms(3_604.0), "1 h 0 min 4 s");
+        assert_eq!(hms(3_599.6), "1 h 0 min 0 s");
+        assert_eq!(hms(59.0), "59 s");
+        assert_eq!(si::Hours::new(1.5).display_compound::<(Hours, Minutes)>().to_string(), "1 h 30 min");
+
+        assert_eq!(Feet::new(5_281.0).display_compound::<(Miles, Yards, Feet, Inches)>().to_string(), "1 mi 0 yd 1 ft 0 in");
+        assert_eq!(si::Meters::new(1_609.0).display_compound::<(Miles, si::Meters)>().to_string(), "1609 m");
+        assert_eq!(Seconds::new(7_200_u32).display_compound::<(Hours, Minutes)>().to_string(), "2 h 0 min");
+
+        assert_eq!(ft_in(f64::NAN), "NaN ft");
+        assert_eq!(ft_in(f64::INFINITY), "inf ft");
+        assert_eq!(format!("[{:>8}]", Feet::new(f64::NEG_INFINITY).display_compound::<(Feet, Inches)>()), "[ -inf ft]");
+    }
+
+    #[test]
+    fn display() {
+        let km = Kilometers::new(3.0);
+        assert_eq!(format!("{:.1}", km.display_with(UnitFormat::new(UnitStyle::Name))), "3.0 kilometers");
+        let a = Acceleration::<SI>::new(9.81);
+        assert_eq!(a.display_with(UnitFormat::new(UnitStyle::Unicode)).to_string(), "9.81 m/s²");
+        assert_eq!(format!("[{:<10}]", a.display_with(UnitFormat::new(UnitStyle::Unicode))), "[9.81 m/s² ]");
+        assert_eq!(format!("[{:>12}]", Kilometers::new(1.5).display_engineering()), "[      1.5 km]");
+        assert_eq!(format!("[{:>8.0}]", crate::system::imperial::Feet::new(-0.5).display_compound::<(crate::system::imperial::Feet, crate::system::imperial::Inches)>()), "[   -6 in]");
+    }
+
+    #[test]
+    fn locales() {
+        use crate::locale::{French, Spanish};
+        use crate::system::{imperial::{AcreFeetPerDay, Feet, Slugs}, us_engineering::PoundsForce};
+        let name = UnitFormat::new(UnitStyle::Name);
+        let a = Acceleration::<SI>::new(9.81);
+        assert_eq!(a.display_with(name).to_string(), "9.81 meters per second squared");
+        let (french, spanish) = (French::default(), Spanish::default());
+        assert_eq!(a.display_with(name).locale(&french).to_string(), "9,81 mètres par seconde carrée");
+        assert_eq!(a.display_with(name).locale(&spanish).to_string(), "9,81 metros por segundo al cuadrado");
+        assert_eq!(format!("{:.1}", Feet::new(1.5).display_with(name).locale(&french)), "1,5 pied");
+        assert_eq!(format!("{:.0}", Feet::new(1.0).display_with(name)), "1 foot");
+        assert_eq!(format!("{:.0}", Feet::new(3.0).display_with(name)), "3 feet");
+        assert_eq!(format!("{:.0}", Area::<Imp>::new(2_500.0).display_with(name).locale(&spanish)), "2500 pies cuadrados");
+        assert_eq!(format!("{:.0}", Area::<Imp>::new(2_500.0).display_with(name.group_digits(true)).locale(&spanish)), "2.500 pies cuadrados");
+        assert_eq!(format!("{:.0}", Flow::<Imp>::new(3.0).display_with(name).locale(&french)), "3 pieds cubes par seconde");
+        assert_eq!(format!("{:.0}", Slugs::new(1_000_u32).display_with(name.group_digits(true)).locale(&french)), "1\u{202f}000 slugs");
+        assert_eq!(format!("{:.0}", AcreFeetPerDay::new(2.0).display_with(name)), "2 acre-feet per day");
+        assert_eq!(format!("{:.0}", PoundsForce::new(2.0).display_with(name)), "2 pounds-force");
+        // locales only change the number in symbol styles
+        let ascii = UnitFormat::new(UnitStyle::Ascii).group_digits(true);
+        assert_eq!(format!("{:.1}", Kilometers::new(-1_234.5).display_with(ascii).locale(&french)), "-1\u{202f}234,5 km");
+    }
+}
@@ -30,4 +30,10 @@ pub use qnty::Qnty;
 pub mod unit;
 pub mod system;
 pub mod conversion;
+pub use conversion::{factor, convert_slice};
+#[cfg(feature = "rayon")]
+pub use conversion::par_convert_slice;
 pub mod dimension;
+pub mod format;
+pub mod locale;
+pub mod parse;
@@ -0,0 +1,395 @@
+//! Language-specific unit names and number formatting.
+//!
+//! A [`Locale`] is passed to the formatter that uses it (see [`QntyDisplay::locale`]),
+//! so different locales can be used side by side.
+//!
+//! ```
+//! use furlong::{format::{UnitFormat, UnitStyle}, locale::{French, Spanish}, system::imperial::{Feet, FeetPerSecond}};
+//!
+//! let names = UnitFormat::new(UnitStyle::Name);
+//! let v = FeetPerSecond::new(1_250.5);
+//! assert_eq!(v.display_with(names).to_string(), "1250.50 feet per second");
+//! assert_eq!(v.display_with(names.group_digits(true)).to_string(), "1,250.50 feet per second");
+//! assert_eq!(v.display_with(names.group_digits(true)).locale(&French::default()).to_string(), "1\u{202f}250,50 pieds par seconde");
+//! assert_eq!(Feet::new(1.0).display_with(names).locale(&Spanish::default()).to_string(), "1,00 pie");
+//! ```
+//!
+//! The French and Spanish names of units that are not built in can be added
+//! with a [`Translation`]:
+//! ```
+//! # use furlong::{format::{UnitFormat, UnitStyle}, locale::{French, Translation}, system::imperial::Furlongs};
+//! let french = French::default().with(Translation::new("furlong", "stade", "stades"));
+//! let names = UnitFormat::new(UnitStyle::Name);
+//! assert_eq!(Furlongs::new(3.0).display_with(names).locale(&french).to_string(), "3,00 stades");
+//! ```
+//!
+//! [`QntyDisplay::locale`]: crate::format::QntyDisplay::locale
+
+use crate::format::UnitPart;
+
+/// Unit names and number formatting of a language
+pub trait Locale {
+    /// Name of the unit `part`, raised to its power, e.g. "square meters" for a
+    /// meter to the power 2 when `plural`. Units are identified by their English
+    /// name ([`BaseUnitInfo::NAME`] or [`ScaledUnitInfo::NAME`]).
+    ///
+    /// `denominator` is set for units written after [`per`](Locale::per),
+    /// whose power is then positive.
+    ///
+    /// [`BaseUnitInfo::NAME`]: crate::unit::BaseUnitInfo::NAME
+    /// [`ScaledUnitInfo::NAME`]: crate::unit::ScaledUnitInfo::NAME
+    fn unit_name(&self, part: &UnitPart, plural: bool, denominator: bool) -> String;
+
+    /// Word introducing the units of a denominator
+    fn per(&self) -> &str;
+
+    /// Whether a unit following `value` is plural
+    fn is_plural(&self, value: f64) -> bool {
+        value.abs() != 1.0
+    }
+
+    /// Separator between the integer and fractional digits of a number
+    fn decimal_separator(&self) -> &str;
+
+    /// Separator between groups of three integer digits
+    fn group_separator(&self) -> &str;
+
+    /// `number`, as written by `Display` (e.g. "-1234.5"), with this locale's decimal
+    /// separator, and its integer digits grouped in threes if `group_digits`
+    fn number(&self, number: &str, group_digits: bool) -> String {
+        let (sign, digits) = match number.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", number),
+        };
+        let (int, fraction) = match digits.split_once('.') {
+            Some((int, fraction)) => (int, Some(fraction)),
+            None => (digits, None),
+        };
+        if !int.bytes().all(|b| b.is_ascii_digit()) {
+            // inf, NaN or an exponent
+            return number.replace('.', self.decimal_separator());
+        }
+        let mut out = String::from(sign);
+        for (i, digit) in int.chars().enumerate() {
+            if group_digits && i > 0 && (int.len() - i) % 3 == 0 {
+                out.push_str(self.group_separator());
+            }
+            out.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            out.push_str(self.decimal_separator());
+            out.push_str(fraction);
+        }
+        out
+    }
+}
+
+/// English names, as written by the unit types themselves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct English;
+
+impl Locale for English {
+    fn unit_name(&self, part: &UnitPart, plural: bool, denominator: bool) -> String {
+        let name = match (plural, part.plural) {
+            (true, Some(name)) => name.to_string(),
+            (true, None) => english_plural(part.name),
+            (false, _) => part.name.to_string(),
+        };
+        match (part.power, denominator) {
+            (1, _) => name,
+            (2, false) => format!("square {}", name),
+            (3, false) => format!("cubic {}", name),
+            (2, true) => format!("{} squared", name),
+            (3, true) => format!("{} cubed", name),
+            (n, _) => format!("{} to the power {}", name, n),
+        }
+    }
+
+    fn per(&self) -> &str {
+        "per"
+    }
+
+    fn decimal_separator(&self) -> &str {
+        "."
+    }
+
+    fn group_separator(&self) -> &str {
+        ","
+    }
+}
+
+/// Regular plural of the noun in `name`, e.g. "inches per hour"
+fn english_plural(name: &str) -> String {
+    let noun_end = [" per ", " of "].iter().filter_map(|word| name.find(word)).min().unwrap_or(name.len());
+    let (head, tail) = name.split_at(noun_end);
+    let (rest, noun) = head.split_at(head.rfind([' ', '-']).map_or(0, |i| i + 1));
+    let noun = match noun {
+        n if n.ends_with('s') || n.ends_with('x') || n.ends_with("ch") || n.ends_with("sh") => format!("{}es", n),
+        n => format!("{}s", n),
+    };
+    format!("{}{}{}", rest, noun, tail)
+}
+
+/// Name of a unit in a language with grammatical gender
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Translation {
+    /// English name of the unit ([`BaseUnitInfo::NAME`] or [`ScaledUnitInfo::NAME`])
+    ///
+    /// [`BaseUnitInfo::NAME`]: crate::unit::BaseUnitInfo::NAME
+    /// [`ScaledUnitInfo::NAME`]: crate::unit::ScaledUnitInfo::NAME
+    pub english: &'static str,
+    pub singular: &'static str,
+    pub plural: &'static str,
+    pub feminine: bool,
+}
+
+impl Translation {
+    /// The masculine name of the unit named `english`
+    pub const fn new(english: &'static str, singular: &'static str, plural: &'static str) -> Self {
+        Translation { english, singular, plural, feminine: false }
+    }
+
+    /// This name, with feminine gender
+    pub const fn feminine(self) -> Self {
+        Translation { feminine: true, ..self }
+    }
+}
+
+macro_rules! translations {
+    ($($english:literal => $singular:literal $plural:literal $($feminine:ident)?),+ $(,)?) => {
+        &[$(Translation {
+            english: $english,
+            singular: $singular,
+            plural: $plural,
+            feminine: translations!(@gender $($feminine)?),
+        }),+]
+    };
+    (@gender f) => { true };
+    (@gender) => { false };
+}
+
+/// The translation of `name` in `added` or else `table` and whether it is feminine,
+/// or `name` itself if there is none. Names such as "cubic foot per second" are
+/// translated as two names joined by `per`.
+fn translate(added: &[Translation], table: &[Translation], per: &str, name: &str, plural: bool) -> (String, bool) {
+    let find = |table: &[Translation]| table.iter().rev().find(|translation| translation.english == name).copied();
+    if let Some(translation) = find(added).or_else(|| find(table)) {
+        let word = if plural { translation.plural } else { translation.singular };
+        return (word.to_string(), translation.feminine);
+    }
+    match name.split_once(" per ") {
+        Some((head, tail)) => {
+            let (head, feminine) = translate(added, table, per, head, plural);
+            (format!("{} {} {}", head, per, translate(added, table, per, tail, false).0), feminine)
+        },
+        None => (name.to_string(), false),
+    }
+}
+
+/// French names, with a comma as decimal separator
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct French {
+    added: Vec<Translation>,
+}
+
+impl French {
+    /// Also translate the unit of `translation`, in place of any built-in name
+    pub fn with(mut self, translation: Translation) -> Self {
+        self.added.push(translation);
+        self
+    }
+}
+
+const FRENCH: &[Translation] = translations![
+    "meter" => "mètre" "mètres", "kilometer" => "kilomètre" "kilomètres",
+    "centimeter" => "centimètre" "centimètres", "millimeter" => "millimètre" "millimètres",
+    "foot" => "pied" "pieds", "inch" => "pouce" "pouces", "yard" => "yard" "yards",
+    "mile" => "mille" "milles", "chain" => "chaîne" "chaînes" f, "furlong" => "furlong" "furlongs",
+    "fathom" => "brasse" "brasses" f, "nautical mile" => "mille marin" "milles marins",
+    "astronomical unit" => "unité astronomique" "unités astronomiques" f,
+    "light-year" => "année-lumière" "années-lumière" f, "parsec" => "parsec" "parsecs",
+    "gram" => "gramme" "grammes", "kilogram" => "kilogramme" "kilogrammes",
+    "pound" => "livre" "livres" f, "slug" => "slug" "slugs",
+    "second" => "seconde" "secondes" f, "minute" => "minute" "minutes" f, "hour" => "heure" "heures" f,
+    "day" => "jour" "jours", "week" => "semaine" "semaines" f,
+    "julian year" => "année julienne" "années juliennes" f,
+    "common year" => "année commune" "années communes" f,
+    "leap year" => "année bissextile" "années bissextiles" f,
+    "knot" => "nœud" "nœuds", "liter" => "litre" "litres", "gallon" => "gallon" "gallons",
+    "cubic meter" => "mètre cube" "mètres cubes", "cubic foot" => "pied cube" "pieds cubes",
+    "million gallons" => "million de gallons" "millions de gallons",
+    "acre-foot" => "acre-pied" "acres-pieds",
+    "kilogram-force" => "kilogramme-force" "kilogrammes-force",
+    "pound-force" => "livre-force" "livres-force" f,
+    "watt" => "watt" "watts", "electronvolt" => "électronvolt" "électronvolts",
+];
+
+impl Locale for French {
+    fn unit_name(&self, part: &UnitPart, plural: bool, _denominator: bool) -> String {
+        let (name, feminine) = translate(&self.added, FRENCH, self.per(), part.name, plural);
+        let square = match (feminine, plural) {
+            (false, false) => "carré",
+            (false, true) => "carrés",
+            (true, false) => "carrée",
+            (true, true) => "carrées",
+        };
+        match part.power {
+            1 => name,
+            2 => format!("{} {}", name, square),
+            3 => format!("{} {}", name, if plural { "cubes" } else { "cube" }),
+            n => format!("{} à la puissance {}", name, n),
+        }
+    }
+
+    fn per(&self) -> &str {
+        "par"
+    }
+
+    /// Values below 2 take the singular, e.g. "1,5 mètre"
+    fn is_plural(&self, value: f64) -> bool {
+        value.abs() >= 2.0
+    }
+
+    fn decimal_separator(&self) -> &str {
+        ","
+    }
+
+    /// A narrow no-break space
+    fn group_separator(&self) -> &str {
+        "\u{202f}"
+    }
+}
+
+/// Spanish names, with a comma as decimal separator
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Spanish {
+    added: Vec<Translation>,
+}
+
+impl Spanish {
+    /// Also translate the unit of `translation`, in place of any built-in name
+    pub fn with(mut self, translation: Translation) -> Self {
+        self.added.push(translation);
+        self
+    }
+}
+
+const SPANISH: &[Translation] = translations![
+    "meter" => "metro" "metros", "kilometer" => "kilómetro" "kilómetros",
+    "centimeter" => "centímetro" "centímetros", "millimeter" => "milímetro" "milímetros",
+    "foot" => "pie" "pies", "inch" => "pulgada" "pulgadas" f, "yard" => "yarda" "yardas" f,
+    "mile" => "milla" "millas" f, "chain" => "cadena" "cadenas" f, "furlong" => "furlong" "furlongs",
+    "fathom" => "braza" "brazas" f, "nautical mile" => "milla náutica" "millas náuticas" f,
+    "astronomical unit" => "unidad astronómica" "unidades astronómicas" f,
+    "light-year" => "año luz" "años luz", "parsec" => "pársec" "pársecs",
+    "gram" => "gramo" "gramos", "kilogram" => "kilogramo" "kilogramos",
+    "pound" => "libra" "libras" f, "slug" => "slug" "slugs",
+    "second" => "segundo" "segundos", "minute" => "minuto" "minutos", "hour" => "hora" "horas" f,
+    "day" => "día" "días", "week" => "semana" "semanas" f,
+    "julian year" => "año juliano" "años julianos",
+    "common year" => "año común" "años comunes",
+    "leap year" => "año bisiesto" "años bisiestos",
+    "knot" => "nudo" "nudos", "liter" => "litro" "litros", "gallon" => "galón" "galones",
+    "cubic meter" => "metro cúbico" "metros cúbicos", "cubic foot" => "pie cúbico" "pies cúbicos",
+    "million gallons" => "millón de galones" "millones de galones",
+    "acre-foot" => "acre-pie" "acres-pies",
+    "kilogram-force" => "kilogramo-fuerza" "kilogramos-fuerza",
+    "pound-force" => "libra-fuerza" "libras-fuerza" f,
+    "watt" => "vatio" "vatios", "electronvolt" => "electronvoltio" "electronvoltios",
+];
+
+impl Locale for Spanish {
+    fn unit_name(&self, part: &UnitPart, plural: bool, denominator: bool) -> String {
+        let (name, feminine) = translate(&self.added, SPANISH, self.per(), part.name, plural);
+        let adjective = |masculine: &str, feminine_stem: &str| {
+            let stem = if feminine { feminine_stem } else { masculine };
+            if plural { format!("{}s", stem) } else { stem.to_string() }
+        };
+        match (part.power, denominator) {
+            (1, _) => name,
+            (2, false) => format!("{} {}", name, adjective("cuadrado", "cuadrada")),
+            (3, false) => format!("{} {}", name, adjective("cúbico", "cúbica")),
+            (2, true) => format!("{} al cuadrado", name),
+            (3, true) => format!("{} al cubo", name),
+            (n, _) => format!("{} a la potencia {}", name, n),
+        }
+    }
+
+    fn per(&self) -> &str {
+        "por"
+    }
+
+    fn decimal_separator(&self) -> &str {
+        ","
+    }
+
+    fn group_separator(&self) -> &str {
+        "."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(name: &'static str, power: i8) -> UnitPart {
+        UnitPart { name, symbol: "", power, prefix: None, plural: None }
+    }
+
+    #[test]
+    fn plurals() {
+        assert_eq!(english_plural("meter"), "meters");
+        assert_eq!(english_plural("inch per hour"), "inches per hour");
+        assert_eq!(english_plural("light-year"), "light-years");
+        assert_eq!(english_plural("atomic unit of time"), "atomic units of time");
+        assert_eq!(english_plural("electron mass"), "electron masses");
+
+        // irregular plurals are given by the unit
+        let foot = UnitPart { plural: Some("feet"), ..part("foot", 1) };
+        assert_eq!(English.unit_name(&foot, true, false), "feet");
+        assert_eq!(English.unit_name(&foot, false, false), "foot");
+        assert_eq!(English.unit_name(&UnitPart { power: 3, ..foot }, true, false), "cubic feet");
+    }
+
+    #[test]
+    fn names() {
+        let (french, spanish) = (French::default(), Spanish::default());
+        assert_eq!(English.unit_name(&part("meter", 2), true, false), "square meters");
+        assert_eq!(English.unit_name(&part("second", 2), false, true), "second squared");
+        assert_eq!(french.unit_name(&part("meter", 2), true, false), "mètres carrés");
+        assert_eq!(french.unit_name(&part("second", 2), false, true), "seconde carrée");
+        assert_eq!(french.unit_name(&part("cubic foot per second", 1), true, false), "pieds cubes par seconde");
+        assert_eq!(french.unit_name(&part("hyl", 1), true, false), "hyl");
+        assert_eq!(spanish.unit_name(&part("inch", 2), true, false), "pulgadas cuadradas");
+        assert_eq!(spanish.unit_name(&part("foot", 3), false, false), "pie cúbico");
+        assert_eq!(spanish.unit_name(&part("second", 2), false, true), "segundo al cuadrado");
+        assert_eq!(spanish.unit_name(&part("million gallons per day", 1), true, false), "millones de galones por día");
+    }
+
+    #[test]
+    fn added_translations() {
+        let french = French::default()
+            .with(Translation::new("hyl", "hyl", "hyls"))
+            .with(Translation::new("meter", "mètre-étalon", "mètres-étalons"));
+        assert_eq!(french.unit_name(&part("hyl", 1), true, false), "hyls");
+        assert_eq!(french.unit_name(&part("meter", 1), false, false), "mètre-étalon");
+        assert_eq!(french.unit_name(&part("hyl per second", 1), true, false), "hyls par seconde");
+        let spanish = Spanish::default().with(Translation::new("hyl", "hyl", "hyls").feminine());
+        assert_eq!(spanish.unit_name(&part("hyl", 2), true, false), "hyls cuadradas");
+    }
+
+    #[test]
+    fn numbers() {
+        let (french, spanish) = (French::default(), Spanish::default());
+        assert_eq!(English.number("1234567.891", true), "1,234,567.891");
+        assert_eq!(English.number("1234567.891", false), "1234567.891");
+        assert_eq!(English.number("-123", true), "-123");
+        assert_eq!(English.number("-1234", true), "-1,234");
+        assert_eq!(french.number("1234.5", true), "1\u{202f}234,5");
+        assert_eq!(french.number("1234.5", false), "1234,5");
+        assert_eq!(spanish.number("1000000", true), "1.000.000");
+        assert_eq!(spanish.number("1e-7", true), "1e-7");
+        assert_eq!(spanish.number("NaN", true), "NaN");
+        assert_eq!(spanish.number("inf", true), "inf");
+    }
+}
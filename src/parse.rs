@@ -0,0 +1,697 @@
+//! Parsing quantities from text, e.g. `"3.5 km".parse::<Qnty<Meters>>()`.
+//!
+//! Unit symbols are looked up among the units defined in [`system`](crate::system),
+//! by the symbol they are displayed with ([`UnitInfo::abbr`]) or written in the
+//! ASCII or Unicode [`UnitStyle`]. More units can be added to a [`UnitRegistry`]. Symbols can be combined into expressions
+//! such as "kg*m/s^2", "ft³/s" or "m s-1", see [`DynUnit::parse`].
+
+use std::any::TypeId;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use num_traits::NumCast;
+
+use crate::{
+    conversion::*,
+    dimension::DimensionVector,
+    format::{UnitFormat, UnitStyle, PREFIXES},
+    qnty::Qnty,
+    system::{si, imperial, us_engineering},
+    unit::*,
+};
+
+/// A unit that quantities can be parsed in
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitSymbol {
+    pub symbol: String,
+    /// Exponents of mass, length and time
+    pub dimension: [i8; 3],
+    /// Value of 1 of this unit in SI base units (kilograms, meters and seconds)
+    pub factor: f64,
+    /// See [`BaseUnitInfo::PREFIX`]; units with `Some(0)` accept any SI prefix
+    pub prefix: Option<i8>,
+}
+
+impl UnitSymbol {
+    /// The symbols `U` is written with
+    pub fn of<U>() -> Vec<UnitSymbol>
+    where
+        U: UnitInfo + ConversionTo<SystemUnit<si::System, U::Dim>>,
+        U::Dim: DimensionVector,
+    {
+        let mut symbols = vec![U::abbr()];
+        for style in [UnitStyle::Ascii, UnitStyle::Unicode] {
+            let symbol = U::format(UnitFormat::new(style));
+            if !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+        let parts: Vec<_> = U::parts().into_iter().filter(|part| part.power != 0).collect();
+        let prefix = match parts.as_slice() {
+            [part] if part.power == 1 => part.prefix,
+            _ => None,
+        };
+        symbols.into_iter()
+            .map(|symbol| UnitSymbol::named::<U>(&symbol, prefix))
+            .collect()
+    }
+
+    /// `U` written as `symbol`, e.g. "N" for [`si::Newtons`]
+    pub fn named<U>(symbol: &str, prefix: Option<i8>) -> UnitSymbol
+    where
+        U: Unit + ConversionTo<SystemUnit<si::System, U::Dim>>,
+        U::Dim: DimensionVector,
+    {
+        UnitSymbol {
+            symbol: symbol.to_string(),
+            dimension: <U::Dim as DimensionVector>::EXPONENTS,
+            factor: Conversion::<U, SystemUnit<si::System, U::Dim>>::REAL,
+            prefix,
+        }
+    }
+}
+
+macro_rules! unit_symbols {
+    ($($U:ty),+ $(,)?) => {
+        (
+            vec![$(UnitSymbol::of::<$U>()),+].into_iter().flatten().collect(),
+            vec![$((TypeId::of::<$U>(), <$U as UnitInfo>::abbr())),+],
+        )
+    };
+}
+
+/// The units defined in [`system`](crate::system), with the symbol of each unit type
+struct Builtin {
+    registry: UnitRegistry,
+    symbols: Vec<(TypeId, String)>,
+}
+
+fn builtin() -> &'static Builtin {
+    static UNITS: OnceLock<Builtin> = OnceLock::new();
+    UNITS.get_or_init(|| {
+        let (units, symbols) = unit_symbols![
+            si::Meters, si::Centimeters, si::Kilometers, si::NauticalMiles,
+            si::AstronomicalUnits, si::LightYears, si::Parsecs,
+            imperial::Inches, imperial::Feet, imperial::Yards, imperial::Fathoms,
+            imperial::Chains, imperial::Furlongs, imperial::Miles,
+            si::Liters, imperial::Gallons, imperial::AcreFeet,
+            si::Seconds, si::Minutes, si::Hours, si::Days, si::Weeks,
+            si::JulianYears, si::CommonYears, si::LeapYears,
+            crate::system::Mass<si::System>, si::Grams, imperial::Slugs, us_engineering::PoundsMass,
+            si::MetersPerSecond, imperial::FeetPerSecond, si::Knots,
+            si::MillimetersPerHour, imperial::InchesPerHour,
+            si::CubicMetersPerSecond, si::LitersPerSecond, imperial::CubicFeetPerSecond,
+            imperial::GallonsPerMinute, imperial::MillionGallonsPerDay, imperial::AcreFeetPerDay,
+            si::Newtons, si::KilogramsForce, us_engineering::PoundsForce,
+            si::Joules, si::Watts,
+        ];
+        let mut registry = UnitRegistry { units };
+        registry.insert(UnitSymbol::named::<si::Newtons>("N", Some(0)));
+        registry.insert(UnitSymbol::named::<si::Joules>("J", Some(0)));
+        registry.insert(UnitSymbol::named::<imperial::CubicFeetPerSecond>("cfs", None));
+        Builtin { registry, symbols }
+    })
+}
+
+/// Symbol of `U` if it is one of the units defined in [`system`](crate::system)
+pub(crate) fn builtin_symbol<U: 'static>() -> Option<&'static str> {
+    let id = TypeId::of::<U>();
+    builtin().symbols.iter()
+        .find(|(unit, _)| *unit == id)
+        .map(|(_, symbol)| symbol.as_str())
+}
+
+/// A table of the unit symbols that quantities can be parsed in. 
+/// 
+/// The [`default`](UnitRegistry::default) registry holds the units defined in 
+/// [`system`](crate::system) (see [`unit_symbols`]), and is the one used by 
+/// [`FromStr`]. Other units can be added to a registry of your own:
+/// ```
+/// # use furlong::{Qnty, parse::{UnitRegistry, UnitSymbol}, system::{si::Meters, imperial::Furlongs}};
+/// let mut units = UnitRegistry::default();
+/// assert!(units.parse_qnty::<Meters, f64>("2 furlongs").is_err());
+/// units.insert(UnitSymbol::named::<Furlongs>("furlongs", None));
+/// let length: Qnty<Meters> = units.parse_qnty("2 furlongs").unwrap();
+/// assert!((length.value() - 402.336).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitRegistry {
+    units: Vec<UnitSymbol>,
+}
+
+impl UnitRegistry {
+    /// A registry with no units
+    pub fn new() -> Self {
+        UnitRegistry { units: Vec::new() }
+    }
+
+    /// The registry of the units defined in [`system`](crate::system)
+    pub fn builtin() -> &'static UnitRegistry {
+        &builtin().registry
+    }
+
+    /// Add `unit`, which takes precedence over any unit already written with the same symbol
+    pub fn insert(&mut self, unit: UnitSymbol) {
+        self.units.push(unit);
+    }
+
+    /// Add the symbols `U` is written with, see [`UnitSymbol::of`]
+    pub fn insert_unit<U>(&mut self)
+    where
+        U: UnitInfo + ConversionTo<SystemUnit<si::System, U::Dim>>,
+        U::Dim: DimensionVector,
+    {
+        self.units.extend(UnitSymbol::of::<U>());
+    }
+
+    /// All units in this registry
+    pub fn units(&self) -> &[UnitSymbol] {
+        &self.units
+    }
+
+    /// Find the unit written as `symbol`
+    pub fn find(&self, symbol: &str) -> Option<&UnitSymbol> {
+        self.units.iter().rev().find(|unit| unit.symbol == symbol)
+    }
+
+    /// Parse a unit expression with the symbols of this registry, see [`DynUnit::parse`]
+    pub fn parse_unit(&self, expr: &str) -> Result<DynUnit, ParseQntyError> {
+        let expr = expr.trim();
+        if let Some(unit) = self.lookup(expr) {
+            return Ok(DynUnit { symbol: expr.to_string(), ..unit });
+        }
+        let mut parser = Parser { units: self, expr, chars: expr.chars().collect(), pos: 0 };
+        let unit = parser.product()?;
+        match parser.peek() {
+            None => Ok(DynUnit { symbol: expr.to_string(), ..unit }),
+            Some(_) => Err(parser.invalid()),
+        }
+    }
+
+    /// Parse a number followed by a unit with the symbols of this registry, 
+    /// as [`FromStr`] does for [`Qnty`]
+    pub fn parse_qnty<U, T>(&self, s: &str) -> Result<Qnty<U, T>, ParseQntyError>
+    where
+        U: Unit,
+        U::Dim: DimensionVector,
+        GetSystemUnit<U>: ConversionTo<SystemUnit<si::System, U::Dim>>,
+        T: NumCast,
+    {
+        let (number, symbol) = split_number(s)?;
+        let unit = match symbol {
+            "" if <U::Dim as DimensionVector>::EXPONENTS == [0; 3] => DynUnit::dimensionless(),
+            "" => return Err(ParseQntyError::MissingUnit),
+            _ => self.parse_unit(symbol)?,
+        };
+        unit.qnty(number)
+    }
+
+    /// A registered symbol, or one with an SI prefix
+    fn lookup(&self, symbol: &str) -> Option<DynUnit> {
+        let unit = |unit: &UnitSymbol, exponent: i8| DynUnit {
+            symbol: symbol.to_string(),
+            dimension: unit.dimension,
+            factor: unit.factor * 10f64.powi(exponent.into()),
+        };
+        if let Some(found) = self.find(symbol) {
+            return Some(unit(found, 0));
+        }
+        PREFIXES.iter()
+            .filter(|prefix| prefix.exponent != 0)
+            .map(|prefix| (prefix.symbol, prefix.exponent))
+            .chain([("u", -6), ("μ", -6)])
+            .find_map(|(prefix, exponent)| {
+                let found = self.find(symbol.strip_prefix(prefix)?)?;
+                (found.prefix == Some(0)).then(|| unit(found, exponent))
+            })
+    }
+}
+
+impl Default for UnitRegistry {
+    fn default() -> Self {
+        UnitRegistry::builtin().clone()
+    }
+}
+
+/// All units that quantities can be parsed in by default
+pub fn unit_symbols() -> &'static [UnitSymbol] {
+    UnitRegistry::builtin().units()
+}
+
+/// Find the unit written as `symbol` among [`unit_symbols`]
+pub fn find_unit(symbol: &str) -> Option<&'static UnitSymbol> {
+    UnitRegistry::builtin().find(symbol)
+}
+
+/// A unit only known at runtime, e.g. entered by a user
+///
+/// ```
+/// # use furlong::{Qnty, parse::DynUnit, system::{Flow, si::System as SI}};
+/// let unit = DynUnit::parse("ft³/s").unwrap();
+/// assert_eq!(unit.dimension, [0, 3, -1]);
+/// assert!(unit.check::<Flow<SI>>().is_ok());
+/// let flow: Qnty<Flow<SI>> = unit.qnty(100.0).unwrap();
+/// assert!((flow.value() - 2.831_684_659_2).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynUnit {
+    /// The unit as written
+    pub symbol: String,
+    /// Exponents of mass, length and time
+    pub dimension: [i8; 3],
+    /// Value of 1 of this unit in SI base units (kilograms, meters and seconds)
+    pub factor: f64,
+}
+
+impl DynUnit {
+    fn dimensionless() -> Self {
+        DynUnit { symbol: String::new(), dimension: [0; 3], factor: 1.0 }
+    }
+
+    /// Parse a unit expression such as "kg*m/s^2", "N·m", "ft³/s" or "m s-1"
+    ///
+    /// Symbols are those of [`unit_symbols`] (see [`UnitRegistry::parse_unit`] for others), optionally with an SI prefix (e.g. "kN"
+    /// or "µm") if the unit takes one. They are combined with `*` or `·` (or just a
+    /// space), `/`, parentheses and integer powers written as `^2`, `²` or `-1`.
+    /// Operators apply from left to right, so "W/m*K" is "(W/m)*K".
+    pub fn parse(expr: &str) -> Result<Self, ParseQntyError> {
+        UnitRegistry::builtin().parse_unit(expr)
+    }
+
+    /// Ok if this unit has the dimension of `U`
+    pub fn check<U: Unit>(&self) -> Result<(), ParseQntyError>
+    where U::Dim: DimensionVector {
+        let expected = <U::Dim as DimensionVector>::EXPONENTS;
+        if self.dimension == expected {
+            Ok(())
+        } else {
+            Err(ParseQntyError::DimensionMismatch { unit: self.symbol.clone(), expected, found: self.dimension })
+        }
+    }
+
+    /// A quantity of `value` in this unit, if it has the dimension of `U`
+    pub fn qnty<U, T>(&self, value: f64) -> Result<Qnty<U, T>, ParseQntyError>
+    where
+        U: Unit,
+        U::Dim: DimensionVector,
+        GetSystemUnit<U>: ConversionTo<SystemUnit<si::System, U::Dim>>,
+        T: NumCast,
+    {
+        self.check::<U>()?;
+        let raw = value * self.factor / Conversion::<GetSystemUnit<U>, SystemUnit<si::System, U::Dim>>::REAL;
+        Some(raw)
+            .filter(|raw| raw.is_finite())
+            .and_then(cast)
+            .map(Qnty::from_raw_value)
+            .ok_or(ParseQntyError::OutOfRange)
+    }
+
+    /// The product of two units, or `None` if an exponent overflows
+    fn mul(self, other: DynUnit) -> Option<Self> {
+        let mut dimension = self.dimension;
+        for (a, b) in dimension.iter_mut().zip(other.dimension) {
+            *a = a.checked_add(b)?;
+        }
+        Some(DynUnit { dimension, factor: self.factor * other.factor, ..self })
+    }
+
+    /// This unit to the power `n`, or `None` if an exponent overflows
+    fn powi(self, n: i8) -> Option<Self> {
+        let mut dimension = self.dimension;
+        for a in dimension.iter_mut() {
+            *a = a.checked_mul(n)?;
+        }
+        Some(DynUnit { dimension, factor: self.factor.powi(n.into()), ..self })
+    }
+}
+
+impl FromStr for DynUnit {
+    type Err = ParseQntyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DynUnit::parse(s)
+    }
+}
+
+impl Display for DynUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.symbol)
+    }
+}
+
+const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// Recursive descent parser for [`DynUnit::parse`]
+struct Parser<'a> {
+    units: &'a UnitRegistry,
+    expr: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next_is(&self, f: impl Fn(char) -> bool) -> bool {
+        self.peek().is_some_and(f)
+    }
+
+    /// Skip whitespace, returning whether there was any
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.next_is(char::is_whitespace) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+
+    fn invalid(&self) -> ParseQntyError {
+        ParseQntyError::InvalidUnit(self.expr.to_string())
+    }
+
+    /// factor (("*" | "·" | "/" | " ") factor)*
+    fn product(&mut self) -> Result<DynUnit, ParseQntyError> {
+        let mut unit = self.factor()?;
+        loop {
+            let spaced = self.skip_whitespace();
+            match self.peek() {
+                Some('*' | '·' | '⋅') => {
+                    self.pos += 1;
+                    let factor = self.factor()?;
+                    unit = unit.mul(factor).ok_or_else(|| self.invalid())?;
+                }
+                Some('/') => {
+                    self.pos += 1;
+                    let factor = self.factor()?.powi(-1).ok_or_else(|| self.invalid())?;
+                    unit = unit.mul(factor).ok_or_else(|| self.invalid())?;
+                }
+                None | Some(')') => return Ok(unit),
+                Some(_) if spaced => {
+                    let factor = self.factor()?;
+                    unit = unit.mul(factor).ok_or_else(|| self.invalid())?;
+                }
+                Some(_) => return Err(self.invalid()),
+            }
+        }
+    }
+
+    /// atom exponent?
+    fn factor(&mut self) -> Result<DynUnit, ParseQntyError> {
+        self.skip_whitespace();
+        let unit = self.atom()?;
+        match self.exponent()? {
+            Some(n) => unit.powi(n).ok_or_else(|| self.invalid()),
+            None => Ok(unit),
+        }
+    }
+
+    /// "(" product ")" | "1" | symbol
+    fn atom(&mut self) -> Result<DynUnit, ParseQntyError> {
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let unit = self.product()?;
+                if self.peek() != Some(')') {
+                    return Err(self.invalid());
+                }
+                self.pos += 1;
+                Ok(unit)
+            }
+            Some('1') => {
+                self.pos += 1;
+                Ok(DynUnit::dimensionless())
+            }
+            _ => {
+                let start = self.pos;
+                while self.next_is(|c| !is_operator(c)) {
+                    // a hyphen starts an exponent ("s-1") but may be part of a symbol ("ac-ft")
+                    if self.peek() == Some('-') && self.chars.get(self.pos + 1).is_some_and(char::is_ascii_digit) {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                if self.pos == start {
+                    return Err(self.invalid());
+                }
+                let symbol: String = self.chars[start..self.pos].iter().collect();
+                self.units.lookup(&symbol).ok_or(ParseQntyError::UnknownUnit(symbol))
+            }
+        }
+    }
+
+    /// "^" integer | superscript integer | integer
+    fn exponent(&mut self) -> Result<Option<i8>, ParseQntyError> {
+        let superscript = match self.peek() {
+            Some('^') => {
+                self.pos += 1;
+                false
+            }
+            Some(c) if c == '⁻' || SUPERSCRIPTS.contains(&c) => true,
+            Some(c) if c == '-' || c.is_ascii_digit() => false,
+            _ => return Ok(None),
+        };
+        let mut digits = String::new();
+        if let Some(sign @ ('-' | '+' | '⁻')) = self.peek() {
+            digits.push(if sign == '⁻' { '-' } else { sign });
+            self.pos += 1;
+        }
+        while let Some(c) = self.peek() {
+            let digit = match superscript {
+                true => SUPERSCRIPTS.iter().position(|&s| s == c).and_then(|d| char::from_digit(d as u32, 10)),
+                false => Some(c).filter(char::is_ascii_digit),
+            };
+            match digit {
+                Some(digit) => digits.push(digit),
+                None => break,
+            }
+            self.pos += 1;
+        }
+        digits.parse().map(Some).map_err(|_| self.invalid())
+    }
+}
+
+fn is_operator(c: char) -> bool {
+    c.is_whitespace() || c.is_ascii_digit() || SUPERSCRIPTS.contains(&c) || "*·⋅/^()⁻".contains(c)
+}
+
+/// Error returned when parsing a [`Qnty`] fails
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseQntyError {
+    /// The text does not start with a number
+    InvalidNumber(String),
+    /// No unit follows the number
+    MissingUnit,
+    /// The unit symbol is not known
+    UnknownUnit(String),
+    /// The unit expression is malformed, e.g. "m/(s"
+    InvalidUnit(String),
+    /// The unit does not have the dimension of the quantity being parsed
+    DimensionMismatch {
+        unit: String,
+        expected: [i8; 3],
+        found: [i8; 3],
+    },
+    /// The value cannot be represented by the quantity's value type
+    OutOfRange,
+}
+
+impl Display for ParseQntyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseQntyError::InvalidNumber(text) => write!(f, "invalid number \"{}\"", text),
+            ParseQntyError::MissingUnit => write!(f, "missing unit"),
+            ParseQntyError::UnknownUnit(unit) => write!(f, "unknown unit \"{}\"", unit),
+            ParseQntyError::InvalidUnit(unit) => write!(f, "invalid unit expression \"{}\"", unit),
+            ParseQntyError::DimensionMismatch { unit, expected, found } => write!(
+                f, "unit \"{}\" has dimension {} but {} was expected",
+                unit, DisplayDimension(*found), DisplayDimension(*expected)
+            ),
+            ParseQntyError::OutOfRange => write!(f, "value out of range"),
+        }
+    }
+}
+
+impl Error for ParseQntyError {}
+
+/// Writes exponents of mass, length and time like "L T^-1"
+pub(crate) struct DisplayDimension(pub [i8; 3]);
+
+impl Display for DisplayDimension {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let parts: Vec<_> = ["M", "L", "T"].iter().zip(self.0)
+            .filter(|(_, power)| *power != 0)
+            .map(|(symbol, power)| if power == 1 { symbol.to_string() } else { format!("{}^{}", symbol, power) })
+            .collect();
+        if parts.is_empty() {
+            write!(f, "1")
+        } else {
+            write!(f, "{}", parts.join(" "))
+        }
+    }
+}
+
+/// Split "3.5 km" or "3.5km" into the number and the unit. The number must be 
+/// finite, so "inf" and "NaN" are not numbers. 
+fn split_number(s: &str) -> Result<(f64, &str), ParseQntyError> {
+    let s = s.trim();
+    let number_len = s.char_indices()
+        .map(|(i, c)| i + c.len_utf8())
+        .rfind(|&i| s[..i].parse::<f64>().is_ok())
+        .ok_or_else(|| ParseQntyError::InvalidNumber(s.split_whitespace().next().unwrap_or("").to_string()))?;
+    let (number, unit) = s.split_at(number_len);
+    match number.parse::<f64>().expect("prefix was parsed") {
+        value if value.is_finite() => Ok((value, unit.trim())),
+        _ => Err(ParseQntyError::InvalidNumber(number.to_string())),
+    }
+}
+
+/// Integers round to the nearest value, as [`Rounding::Nearest`] would
+fn cast<T: NumCast>(raw: f64) -> Option<T> {
+    let truncates = T::from(0.5).and_then(|half| half.to_f64()) == Some(0.0);
+    T::from(if truncates { raw.round() } else { raw })
+}
+
+impl<U, T> FromStr for Qnty<U, T>
+where
+    U: Unit,
+    U::Dim: DimensionVector,
+    GetSystemUnit<U>: ConversionTo<SystemUnit<si::System, U::Dim>>,
+    T: NumCast,
+{
+    type Err = ParseQntyError;
+
+    /// Parse a number followed by a unit, e.g. "3.5 km", "12ft" or "9.8 m/s^2"
+    /// ```
+    /// # use furlong::{Qnty, parse::ParseQntyError, system::{si::Meters, imperial::Feet}};
+    /// let length: Qnty<Meters> = "3.5 km".parse().unwrap();
+    /// assert_eq!(length, Meters::new(3_500.0));
+    /// let stage: Qnty<Feet, i32> = "12ft".parse().unwrap();
+    /// assert_eq!(stage, Feet::new(12));
+    /// assert_eq!("3 s".parse::<Qnty<Meters>>().unwrap_err().to_string(), "unit \"s\" has dimension T but L was expected");
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        UnitRegistry::builtin().parse_qnty(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::{
+        Velocity, Flow,
+        si::{Meters, Kilometers, Seconds, Hours, Newtons, System as SI},
+        imperial::{Feet, Yards, GallonsPerMinute},
+    };
+
+    #[test]
+    fn parse() {
+        assert_eq!("3.5 km".parse::<Qnty<Meters>>(), Ok(Meters::new(3_500.0)));
+        assert_eq!("3.5 km".parse::<Qnty<Kilometers>>().map(|q| q.value()), Ok(3.5));
+        assert_eq!(" 12 ft ".parse::<Qnty<Feet>>(), Ok(Feet::new(12.0)));
+        assert_eq!("-1.5e3m".parse::<Qnty<Meters>>(), Ok(Meters::new(-1_500.0)));
+        approx::assert_relative_eq!(*"12 ft".parse::<Qnty<Meters>>().unwrap().raw_value(), 3.657_6);
+        assert_eq!("2 h".parse::<Qnty<Seconds>>(), Ok(Seconds::new(7_200.0)));
+        assert_eq!("36 in".parse::<Qnty<Yards, i32>>(), Ok(Yards::new(1)));
+        assert_eq!("5 in".parse::<Qnty<Feet, u8>>(), Ok(Feet::new(0_u8)));
+        assert_eq!("5 km".parse::<Qnty<Meters, u8>>(), Err(ParseQntyError::OutOfRange));
+
+        assert_eq!("450 gpm".parse::<Qnty<GallonsPerMinute>>().map(|q| q.value().round()), Ok(450.0));
+        approx::assert_relative_eq!("1 cfs".parse::<Qnty<Flow<SI>>>().unwrap().value(), 0.028_316_846_592);
+        assert_eq!("1 m³/s".parse::<Qnty<Flow<SI>>>(), "1 m^3/s".parse::<Qnty<Flow<SI>>>());
+        assert_eq!("3 m/s".parse::<Qnty<Velocity<SI>>>(), Ok(Velocity::<SI>::new(3.0)));
+        assert_eq!("36 km/h".parse::<Qnty<Velocity<SI>>>().map(|q| q.value().round()), Ok(10.0));
+        assert_eq!("2 kg*m/s^2".parse::<Qnty<Newtons>>(), Ok(Newtons::new(2.0)));
+    }
+
+    #[test]
+    fn expressions() {
+        let unit = |expr: &str| DynUnit::parse(expr).map(|unit| (unit.dimension, unit.factor));
+        assert_eq!(unit("kg*m/s^2"), Ok(([1, 1, -2], 1.0)));
+        assert_eq!(unit("(kg * m) / (s^2)"), unit("kg*m/s^2"));
+        assert_eq!(unit("kg m^2 / s^2"), unit("N·m"));
+        assert_eq!(unit("m s-1"), Ok(([0, 1, -1], 1.0)));
+        assert_eq!(unit("m·s⁻¹"), unit("m s-1"));
+        assert_eq!(unit("m/s/s"), Ok(([0, 1, -2], 1.0)));
+        assert_eq!(unit("1/s"), Ok(([0, 0, -1], 1.0)));
+        assert_eq!(unit("m2"), unit("m²"));
+        assert_eq!(unit("ac-ft/s").map(|(dimension, _)| dimension), Ok([0, 3, -1]));
+
+        let (dimension, factor) = unit("ft³/s").unwrap();
+        assert_eq!(dimension, [0, 3, -1]);
+        approx::assert_relative_eq!(factor, 0.028_316_846_592);
+        approx::assert_relative_eq!(unit("kN").unwrap().1, 1_000.0);
+        approx::assert_relative_eq!(unit("µm").unwrap().1, 1e-6);
+        approx::assert_relative_eq!(unit("um").unwrap().1, 1e-6);
+        approx::assert_relative_eq!(unit("mg").unwrap().1, 1e-6);
+        approx::assert_relative_eq!(unit("MW").unwrap().1, 1e6);
+        approx::assert_relative_eq!(unit("mm/h").unwrap().1, 1.0 / 3_600_000.0);
+
+        let invalid = |expr: &str| Err(ParseQntyError::InvalidUnit(expr.to_string()));
+        assert_eq!(unit("m^"), invalid("m^"));
+        assert_eq!(unit("m/"), invalid("m/"));
+        assert_eq!(unit("m**s"), invalid("m**s"));
+        assert_eq!(unit("(m"), invalid("(m"));
+        assert_eq!(unit("m)"), invalid("m)"));
+        assert_eq!(unit("furlongs/s"), Err(ParseQntyError::UnknownUnit("furlongs".to_string())));
+        assert_eq!(unit("kmin"), Err(ParseQntyError::UnknownUnit("kmin".to_string())));
+    }
+
+    #[test]
+    fn check() {
+        let unit = DynUnit::parse("ft/s").unwrap();
+        assert_eq!(unit.to_string(), "ft/s");
+        assert_eq!(unit.check::<Velocity<SI>>(), Ok(()));
+        assert_eq!(
+            unit.check::<Meters>(),
+            Err(ParseQntyError::DimensionMismatch { unit: "ft/s".to_string(), expected: [0, 1, 0], found: [0, 1, -1] })
+        );
+        approx::assert_relative_eq!(unit.qnty::<Velocity<SI>, f64>(10.0).unwrap().value(), 3.048);
+        assert_eq!("ft / s".parse::<DynUnit>().map(|unit| unit.symbol), Ok("ft / s".to_string()));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!("km".parse::<Qnty<Meters>>(), Err(ParseQntyError::InvalidNumber("km".to_string())));
+        assert_eq!("3".parse::<Qnty<Meters>>(), Err(ParseQntyError::MissingUnit));
+        assert_eq!("3 furlongs".parse::<Qnty<Meters>>(), Err(ParseQntyError::UnknownUnit("furlongs".to_string())));
+        assert_eq!(
+            "3 h".parse::<Qnty<Meters>>(),
+            Err(ParseQntyError::DimensionMismatch { unit: "h".to_string(), expected: [0, 1, 0], found: [0, 0, 1] })
+        );
+        assert_eq!(
+            "3 gpm".parse::<Qnty<Hours>>().unwrap_err().to_string(),
+            "unit \"gpm\" has dimension L^3 T^-1 but T was expected"
+        );
+        assert_eq!("36 km/hr".parse::<Qnty<Velocity<SI>>>(), Err(ParseQntyError::UnknownUnit("hr".to_string())));
+        assert_eq!("3 m/(s".parse::<Qnty<Velocity<SI>>>(), Err(ParseQntyError::InvalidUnit("m/(s".to_string())));
+        for overflow in ["m^100*m^100", "1/m^-128", "(m^127)^2"] {
+            assert_eq!(DynUnit::parse(overflow), Err(ParseQntyError::InvalidUnit(overflow.to_string())));
+        }
+        assert_eq!(ParseQntyError::UnknownUnit("xyz".to_string()).to_string(), "unknown unit \"xyz\"");
+
+        assert_eq!("inf m".parse::<Qnty<Meters>>(), Err(ParseQntyError::InvalidNumber("inf".to_string())));
+        assert_eq!("NaN m".parse::<Qnty<Meters>>(), Err(ParseQntyError::InvalidNumber("NaN".to_string())));
+        assert_eq!("-infinity km".parse::<Qnty<Meters>>(), Err(ParseQntyError::InvalidNumber("-infinity".to_string())));
+        assert_eq!("1e308 au".parse::<Qnty<Meters>>(), Err(ParseQntyError::OutOfRange));
+    }
+
+    #[test]
+    fn registry() {
+        use crate::system::{Length, imperial::Furlongs};
+        let mut units = UnitRegistry::new();
+        assert_eq!(units.parse_qnty::<Meters, f64>("3 m"), Err(ParseQntyError::UnknownUnit("m".to_string())));
+        units.insert_unit::<Meters>();
+        assert_eq!(units.parse_qnty::<Meters, f64>("3 km"), Ok(Meters::new(3_000.0)));
+
+        // later symbols take precedence
+        let mut units = UnitRegistry::default();
+        units.insert(UnitSymbol::named::<Furlongs>("m", None));
+        approx::assert_relative_eq!(units.parse_unit("m").unwrap().factor, 201.168);
+        assert_eq!(units.parse_unit("m/s").map(|unit| unit.dimension), Ok([0, 1, -1]));
+        assert_eq!("3 m".parse::<Qnty<Length<SI>>>(), Ok(Meters::new(3.0)));
+    }
+}
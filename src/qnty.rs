@@ -1,4 +1,4 @@
-use std::fmt::{Display, Debug, Formatter, Result};
+use std::fmt::{Display, Debug, Formatter, LowerExp, UpperExp, Result};
 use std::marker::PhantomData as PD;
 use std::ops::{Add, AddAssign, Mul, Div, SubAssign, Sub};
 
@@ -8,9 +8,34 @@ use num_traits::{Zero, One, AsPrimitive};
 use crate::{
     conversion::*,
     dimension::*,
+    format::{pad, UnitFormat},
+    parse::builtin_symbol,
+    system::{CollapseDimension, Collapsed},
     unit::*
 };
 
+/// A value of type `T` in the unit `U`. 
+/// 
+/// Quantities of primitive values can be built and converted in `const` 
+/// contexts, with [`SystemUnit::new`] or `from_value` (the `const` 
+/// counterpart of [`ScaledUnit::new`]), and read back with `const_value` and `const_value_in`. 
+/// These are the `const` counterparts of [`Qnty::value`] and [`Qnty::value_in`]; they 
+/// cannot share their names, because an inherent method for primitive values would 
+/// clash with the generic one. 
+/// ```
+/// use furlong::{Qnty, system::{si::Meters, imperial::{Feet, GallonsPerMinute}}};
+/// 
+/// const WEIR_HEIGHT: Qnty<Feet> = Feet::new(3.5);
+/// const DESIGN_FLOW: Qnty<GallonsPerMinute> = <Qnty<GallonsPerMinute>>::from_value(450.0);
+/// const WEIR_HEIGHT_M: f64 = WEIR_HEIGHT.const_value_in::<Meters>();
+/// const DESIGN_FLOW_GPM: f64 = DESIGN_FLOW.const_value();
+/// approx::assert_relative_eq!(WEIR_HEIGHT_M, 1.066_8);
+/// approx::assert_relative_eq!(DESIGN_FLOW_GPM, 450.0);
+/// 
+/// // integers are converted exactly
+/// const PIPE_LENGTHS: [Qnty<Feet, u32>; 2] = [<Qnty<Feet, u32>>::from_value(20), <Qnty<Feet, u32>>::from_value(40)];
+/// assert_eq!(PIPE_LENGTHS[1].const_value_in::<furlong::system::imperial::Yards>(), 13);
+/// ```
 #[repr(transparent)]
 pub struct Qnty<U, T = f64> {
     value: T,
@@ -30,22 +55,43 @@ impl<U, T: Copy> Copy for Qnty<U, T> {}
 
 impl<U, T> Qnty<U, T> {
     /// Create a [`Qnty`] with the provided raw value. This 'raw_value' is the value
-    /// of the base unit in the [`System`](crate::unit_system::UnitSystem) associated with
-    /// this [`Unit`]. 
+    /// of the base unit in the [`System`](Unit::System) associated with
+    /// this [`Unit`]. For the value in the unit itself, see `value()`. 
     /// ```
     /// # use furlong::{Qnty, system::{Length, si::System as SI}};
     /// let length = Qnty::<Length<SI>>::from_raw_value(1.0);
     /// assert_eq!(length.raw_value(), &1.0);
     /// ```
-    pub fn from_raw_value(value: T) -> Qnty<U, T> {
+    pub const fn from_raw_value(value: T) -> Qnty<U, T> {
         Qnty { value, unit: PD }
     }
 
     /// Returns a reference to the raw value of this [`Qnty`].
-    pub fn raw_value(&self) -> &T {
+    pub const fn raw_value(&self) -> &T {
         &self.value
     }
 
+    /// Returns the value of this [`Qnty`] in its own unit `U` (e.g. `3.0` for 3 km, 
+    /// whose raw value is `3000.0` m)
+    pub fn value(&self) -> T
+    where
+        U: Unit,
+        GetSystemUnit<U>: ConversionTo<U>,
+        T: Convertible
+    {
+        self.value.convert::<Conversion<GetSystemUnit<U>, U>>()
+    }
+
+    /// Returns the value of this [`Qnty`] in the unit `U2`
+    pub fn value_in<U2>(&self) -> T
+    where
+        U: Unit,
+        GetSystemUnit<U>: ConversionTo<U2>,
+        T: Convertible
+    {
+        self.value.convert::<Conversion<GetSystemUnit<U>, U2>>()
+    }
+
     pub fn into_type<T2>(self) -> Qnty<U, T2>
     where
         T: Into<T2>,
@@ -53,12 +99,61 @@ impl<U, T> Qnty<U, T> {
         Qnty::from_raw_value(self.value.into())
     }
 
-    pub fn into_unit<U2>(self) -> Qnty<U2, T> 
+    /// Convert to the unit `U2`. Raw values are stored in the base unit of each 
+    /// unit's system, so only a change of system affects the raw value. 
+    /// 
+    /// The value is converted in place, so a `Qnty<U, Vec<T>>` is converted 
+    /// without allocating. 
+    pub fn into_unit<U2>(mut self) -> Qnty<U2, T> 
+    where
+        U: Unit,
+        U2: Unit,
+        GetSystemUnit<U>: ConversionTo<GetSystemUnit<U2>>,
+        T: Convertible
+    {
+        self.value.convert_in_place::<Conversion<GetSystemUnit<U>, GetSystemUnit<U2>>>();
+        Qnty::from_raw_value(self.value)
+    }
+
+    /// Re-express this quantity in the base units of the system `S2`, keeping its dimension
+    /// ```
+    /// # use furlong::{Qnty, system::{si::{self, MetersPerSecond}, imperial::Knots}};
+    /// let speed = Knots::new(10.0);
+    /// let si: Qnty<MetersPerSecond> = speed.in_system::<si::System>();
+    /// # approx::assert_relative_eq!(*si.raw_value(), 18_520.0 / 3_600.0);
+    /// ```
+    pub fn in_system<S2>(mut self) -> Qnty<SystemUnit<S2, U::Dim>, T>
     where
-        U: ConversionTo<U2>,
+        U: Unit,
+        GetSystemUnit<U>: ConversionTo<SystemUnit<S2, U::Dim>>,
         T: Convertible
     {
-        Qnty::from_raw_value(self.value.convert::<Conversion<U, U2>>())
+        self.value.convert_in_place::<Conversion<GetSystemUnit<U>, SystemUnit<S2, U::Dim>>>();
+        Qnty::from_raw_value(self.value)
+    }
+
+    /// Convert an integer quantity to the unit `U2` exactly, rounding any 
+    /// fractional result with `rounding`
+    pub fn into_unit_rounded<U2>(self, rounding: Rounding) -> Qnty<U2, T> 
+    where
+        U: Unit,
+        U2: Unit,
+        GetSystemUnit<U>: ConversionTo<GetSystemUnit<U2>>,
+        T: ExactConvertible
+    {
+        Qnty::from_raw_value(self.value.convert_rounded::<Conversion<GetSystemUnit<U>, GetSystemUnit<U2>>>(rounding))
+    }
+
+    /// Convert an integer quantity to the unit `U2`, returning `None` if the 
+    /// result is not a whole number
+    pub fn checked_into_unit<U2>(self) -> Option<Qnty<U2, T>>
+    where
+        U: Unit,
+        U2: Unit,
+        GetSystemUnit<U>: ConversionTo<GetSystemUnit<U2>>,
+        T: ExactConvertible
+    {
+        self.value.checked_convert::<Conversion<GetSystemUnit<U>, GetSystemUnit<U2>>>().map(Qnty::from_raw_value)
     }
 
     pub fn as_type<T2: 'static + Copy>(self) -> Qnty<U, T2>
@@ -79,6 +174,113 @@ impl<U, T> Qnty<U, T> {
 
 }
 
+// `const` construction and conversion of primitive values, see [`Qnty`]
+macro_rules! impl_const_float {
+    ($($T:ty),+) => {$(
+        impl<U: Unit> Qnty<U, $T> {
+            /// Create a [`Qnty`] from a value in the unit `U`
+            pub const fn from_value(value: $T) -> Self
+            where
+                U: ConversionTo<GetSystemUnit<U>>
+            {
+                Qnty::from_raw_value(Self::apply::<Conversion<U, GetSystemUnit<U>>>(value))
+            }
+
+            /// `const` version of [`Qnty::value`]
+            pub const fn const_value(&self) -> $T
+            where
+                GetSystemUnit<U>: ConversionTo<U>
+            {
+                Self::apply::<Conversion<GetSystemUnit<U>, U>>(self.value)
+            }
+
+            /// `const` version of [`Qnty::value_in`], which it cannot be named after as 
+            /// that method applies to every `T`
+            pub const fn const_value_in<U2: Unit>(&self) -> $T
+            where
+                GetSystemUnit<U>: ConversionTo<U2>
+            {
+                Self::apply::<Conversion<GetSystemUnit<U>, U2>>(self.value)
+            }
+
+            const fn apply<C: ConversionFactor>(value: $T) -> $T {
+                (value as f64 * C::REAL) as $T
+            }
+        }
+    )+};
+}
+
+impl_const_float!{f32, f64}
+
+// Integers are converted exactly, truncating like `Convertible::convert`
+macro_rules! impl_const_int {
+    ($scale:ident, $Wide:ty; $($T:ty),+) => {$(
+        impl<U: Unit> Qnty<U, $T> {
+            /// Create a [`Qnty`] from a value in the unit `U`
+            pub const fn from_value(value: $T) -> Self
+            where
+                U: ConversionTo<GetSystemUnit<U>>
+            {
+                Qnty::from_raw_value(Self::apply::<Conversion<U, GetSystemUnit<U>>>(value))
+            }
+
+            /// `const` version of [`Qnty::value`]
+            pub const fn const_value(&self) -> $T
+            where
+                GetSystemUnit<U>: ConversionTo<U>
+            {
+                Self::apply::<Conversion<GetSystemUnit<U>, U>>(self.value)
+            }
+
+            /// `const` version of [`Qnty::value_in`], which it cannot be named after as 
+            /// that method applies to every `T`
+            pub const fn const_value_in<U2: Unit>(&self) -> $T
+            where
+                GetSystemUnit<U>: ConversionTo<U2>
+            {
+                Self::apply::<Conversion<GetSystemUnit<U>, U2>>(self.value)
+            }
+
+            #[allow(clippy::unnecessary_cast)]
+            const fn apply<C: ConversionFactor>(value: $T) -> $T {
+                if C::EXACT {
+                    if let Some((v, _)) = $scale(value as $Wide, C::NUM, C::DEN, Rounding::Trunc) {
+                        if v >= <$T>::MIN as $Wide && v <= <$T>::MAX as $Wide {
+                            return v as $T;
+                        }
+                    }
+                }
+                (value as f64 * C::REAL) as $T
+            }
+        }
+    )+};
+}
+
+impl_const_int!{scale_signed, i128; i8, i16, i32, i64, i128, isize}
+impl_const_int!{scale_unsigned, u128; u8, u16, u32, u64, u128, usize}
+
+impl<S, D, T> Qnty<SystemUnit<S, D>, T> {
+    /// Apply the defining constants of a natural unit system (e.g. c = ħ = 1)
+    /// to re-express this quantity in the system's reduced dimension. 
+    /// The raw value is unchanged, since those constants are all 1. 
+    pub fn collapse(self) -> Qnty<SystemUnit<S, Collapsed<S, D>>, T>
+    where
+        S: CollapseDimension<D>
+    {
+        Qnty::from_raw_value(self.value)
+    }
+
+    /// Reverse of [`Qnty::collapse`]: re-express this quantity as dimension `D2`, 
+    /// which must collapse to the same dimension as this quantity does. 
+    pub fn expand<D2>(self) -> Qnty<SystemUnit<S, D2>, T>
+    where
+        S: CollapseDimension<D> + CollapseDimension<D2>,
+        Collapsed<S, D2>: SameDimension<Collapsed<S, D>>
+    {
+        Qnty::from_raw_value(self.value)
+    }
+}
+
 impl<S, D, T> From<T> for Qnty<SystemUnit<S, D>, T> {
     fn from(value: T) -> Self {
         Qnty::from_raw_value(value)
@@ -178,17 +380,87 @@ where
     }
 }
 
-impl<U: UnitInfo, T: Display> Display for Qnty<U, T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{0:.1$} {2}", self.value, f.precision().unwrap_or(2), <U as UnitInfo>::abbr())
+/// The raw value is stored in the system's base unit, so it is converted 
+/// back to the quantity's own unit (see [`Qnty::value`]) for display
+macro_rules! impl_fmt {
+    ($Trait:ident, $number:ident) => {
+        impl<U, T> $Trait for Qnty<U, T>
+        where
+            U: UnitInfo,
+            GetSystemUnit<U>: ConversionTo<U>,
+            T: Convertible + $Trait
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                let number = $number(f, &self.value());
+                pad(f, &format!("{} {}", number, U::format(UnitFormat::default())))
+            }
+        }
+    };
+}
+
+impl_fmt!{Display, display_number}
+impl_fmt!{LowerExp, lower_exp_number}
+impl_fmt!{UpperExp, upper_exp_number}
+
+/// `value` with 2 decimals by default. See [`Qnty::to_sig_figs`] for a number of 
+/// significant figures instead.
+fn display_number(f: &Formatter<'_>, value: impl Display) -> String {
+    let precision = f.precision().unwrap_or(2);
+    if f.sign_plus() { format!("{:+.*}", precision, value) } else { format!("{:.*}", precision, value) }
+}
+
+fn lower_exp_number(f: &Formatter<'_>, value: impl LowerExp) -> String {
+    match (f.sign_plus(), f.precision()) {
+        (true, Some(precision)) => format!("{:+.*e}", precision, value),
+        (true, None) => format!("{:+e}", value),
+        (false, Some(precision)) => format!("{:.*e}", precision, value),
+        (false, None) => format!("{:e}", value),
+    }
+}
+
+fn upper_exp_number(f: &Formatter<'_>, value: impl UpperExp) -> String {
+    match (f.sign_plus(), f.precision()) {
+        (true, Some(precision)) => format!("{:+.*E}", precision, value),
+        (true, None) => format!("{:+E}", value),
+        (false, Some(precision)) => format!("{:.*E}", precision, value),
+        (false, None) => format!("{:E}", value),
     }
 }
 
-impl<U: UnitInfo, T: Debug> Debug for Qnty<U, T> {
+/// Shows the raw value, which is in the base units of the unit's system (3 km is 
+/// 3000 m in SI), with the dimension (exponents of mass, length and time) and those 
+/// base units as multiples of grams, meters and seconds. 
+/// 
+/// It does not need a [`UnitInfo`]: the unit's symbol is shown if it is known, 
+/// either from [`Unit::SYMBOL`] or because the unit is defined in 
+/// [`system`](crate::system). `Display` shows the value in the unit with its symbol. 
+/// ```
+/// # use furlong::system::si::Kilometers;
+/// assert_eq!(
+///     format!("{:?}", Kilometers::new(3.0)),
+///     "Qnty { unit: \"km\", raw_value: 3000.0, dimension: [0, 1, 0], system: [1000 g, 1 m, 1 s] }"
+/// );
+/// assert_eq!(Kilometers::new(3.0).to_string(), "3.00 km");
+/// ```
+impl<U, T: Debug> Debug for Qnty<U, T>
+where
+    U: Unit + 'static,
+    U::Dim: DimensionVector,
+    U::System: SystemBaseUnits,
+{
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        f.debug_struct("Qnty")
-            .field("value", &self.value)
-            .field("unit", &U::abbr())
+        let system: Vec<_> = <U::System as SystemBaseUnits>::canonical_factors().iter()
+            .zip(["g", "m", "s"])
+            .map(|(factor, unit)| format!("{} {}", factor, unit))
+            .collect();
+        let mut debug = f.debug_struct("Qnty");
+        if let Some(symbol) = U::SYMBOL.or_else(builtin_symbol::<U>) {
+            debug.field("unit", &symbol);
+        }
+        debug
+            .field("raw_value", &self.value)
+            .field("dimension", &<U::Dim as DimensionVector>::EXPONENTS)
+            .field("system", &format_args!("[{}]", system.join(", ")))
             .finish()
     }
 }
@@ -221,7 +493,7 @@ mod test {
         unit::UnitInfo,
         system::{
             Area, Velocity,
-            si::{System as SI, Kilometers, Meters, Seconds},
+            si::{System as SI, Kilometers, Meters, Seconds, Minutes, Hours},
             imperial::{Yards, Feet},
         }
     };
@@ -239,6 +511,9 @@ mod test {
 
         let one_yd_i = Yards::new(1);
         assert_eq!(one_yd_i.raw_value(), &3);
+
+        assert_eq!(Yards::new(1u8).raw_value(), &3);
+        assert_eq!(Kilometers::new(1i128).raw_value(), &1_000);
     }
 
     #[test]
@@ -300,6 +575,22 @@ mod test {
         assert_eq!(perimeter_v, Feet::new(Vec3::<f64>(2.0, 4.0, 6.0)));
     }
 
+    #[test]
+    fn integer_conversions() {
+        use crate::conversion::Rounding;
+        let l = Meters::new(1i32);
+        assert_eq!(l.into_unit::<Feet>().raw_value(), &3);
+        assert_eq!(l.into_unit_rounded::<Feet>(Rounding::Ceil).raw_value(), &4);
+        assert_eq!(l.checked_into_unit::<Feet>(), None);
+        assert_eq!(Meters::new(381i64).checked_into_unit::<Feet>(), Some(Feet::new(1250i64)));
+
+        // `into_unit` truncates toward zero and saturates, like an `as` cast
+        assert_eq!(Meters::new(2i32).into_unit::<Feet>().raw_value(), &6);
+        assert_eq!(Meters::new(-2i32).into_unit::<Feet>().raw_value(), &-6);
+        assert_eq!(Meters::new(2i32).into_unit_rounded::<Feet>(Rounding::Nearest).raw_value(), &7);
+        assert_eq!(Meters::new(i32::MAX).into_unit::<Feet>().raw_value(), &i32::MAX);
+    }
+
     #[test]
     fn subtract_units() {
         let l1 = Meters::new(3.0f32);
@@ -346,6 +637,7 @@ mod test {
         assert_eq!(format!("{q}"), "2 ft");
 
         // test Display for custom type
+        #[allow(dead_code)]
         #[derive(Debug, Clone, Copy)]
         struct MyValue(f32);
         impl Display for MyValue {
@@ -353,8 +645,178 @@ mod test {
                 write!(f, "{self:?}")
             }
         }
+        impl crate::conversion::Convertible for MyValue {
+            fn convert<C: crate::conversion::ConversionFactor>(&self) -> Self {
+                MyValue(self.0.convert::<C>())
+            }
+        }
         let mv = MyValue(3.0);
         let q = Qnty::<Feet, MyValue>::from_raw_value(mv);
         assert_eq!(format!("{q}"), format!("{mv} ft"));
     }
+
+    #[test]
+    fn custom_unit_info() {
+        use crate::{conversion::{ConvRatio, ConversionTo}, dimension::LengthDimension, unit::Unit};
+        // a unit outside of any system, printed in its own unit
+        struct Smoot;
+        impl Unit for Smoot {
+            type System = SI;
+            type Dim = LengthDimension;
+            const SYMBOL: Option<&'static str> = Some("smoot");
+        }
+        impl UnitInfo for Smoot {
+            fn abbr() -> String {
+                String::from("smoot")
+            }
+        }
+        impl ConversionTo<Smoot> for Meters {
+            type Factor = ConvRatio<100, 170>;
+        }
+        let bridge = Qnty::<Smoot>::from_raw_value(620.1);
+        assert_eq!(format!("{bridge:.1}"), "364.8 smoot");
+        assert_eq!(format!("{bridge:.3e}"), "3.648e2 smoot");
+        assert_eq!(format!("{bridge:?}"), "Qnty { unit: \"smoot\", raw_value: 620.1, dimension: [0, 1, 0], system: [1000 g, 1 m, 1 s] }");
+    }
+
+    #[test]
+    fn debug_symbol() {
+        assert_eq!(format!("{:?}", Kilometers::new(2.5)), "Qnty { unit: \"km\", raw_value: 2500.0, dimension: [0, 1, 0], system: [1000 g, 1 m, 1 s] }");
+        assert!(format!("{:?}", Meters::new(1.0)).starts_with("Qnty { unit: \"m\", "));
+        assert!(format!("{:?}", Feet::new(1.0)).starts_with("Qnty { unit: \"ft\", "));
+    }
+
+    #[test]
+    fn debug_without_unit_info() {
+        use crate::{
+            base_unit::{length::MeterBaseUnit, time::SecondBaseUnit},
+            conversion::ConvRatio,
+            dimension::{MassBaseDimension, MassDimension},
+            unit::{BaseUnitTag, MakeSystem, ScaledUnit, SystemUnit},
+        };
+        // a base unit and scaled unit without names or symbols
+        struct StoneBaseUnit;
+        impl BaseUnitTag for StoneBaseUnit {
+            type Dimension = MassBaseDimension;
+            type Canonical = ConvRatio<635_029_318, 100_000>;
+        }
+        type Stones = SystemUnit<MakeSystem<StoneBaseUnit, MeterBaseUnit, SecondBaseUnit>, MassDimension>;
+        type Hundredweights = ScaledUnit<Stones, 8>;
+
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Weighing {
+            weight: Qnty<Hundredweights>,
+        }
+        let weighing = Weighing { weight: Hundredweights::new(2.0) };
+        assert_eq!(
+            format!("{:?}", weighing),
+            "Weighing { weight: Qnty { raw_value: 16.0, dimension: [1, 0, 0], system: [6350.29318 g, 1 m, 1 s] } }"
+        );
+        assert_eq!(format!("{:?}", Qnty::<Area<SI>, i32>::from_raw_value(2)), "Qnty { raw_value: 2, dimension: [0, 2, 0], system: [1000 g, 1 m, 1 s] }");
+    }
+
+    #[test]
+    fn format_flags() {
+        use crate::system::si::{Meters, Kilometers};
+        let m = Meters::new(3.5);
+        assert_eq!(format!("{:>10}|", m), "    3.50 m|");
+        assert_eq!(format!("[{:10}]", m), "[    3.50 m]");
+        assert_eq!(format!("[{:<10}]", m), "[3.50 m    ]");
+        assert_eq!(format!("[{:*^11.1}]", m), "[***3.5 m***]");
+        assert_eq!(format!("{:+.1}", m), "+3.5 m");
+        assert_eq!(format!("{:+.1}", Meters::new(-3.5)), "-3.5 m");
+        assert_eq!(format!("{:+010.1}", m), "+00003.5 m");
+        assert_eq!(format!("{:08.1}", Meters::new(-3.5)), "-003.5 m");
+        assert_eq!(format!("{:3}", m), "3.50 m");
+        assert_eq!(format!("{:>8}", Kilometers::new(1.5)), " 1.50 km");
+
+        assert_eq!(format!("{:e}", Meters::new(1_234.5)), "1.2345e3 m");
+        assert_eq!(format!("{:.2E}", Kilometers::new(0.012_345)), "1.23E-2 km");
+        assert_eq!(format!("{:+.1e}", Meters::new(1_500_u32)), "+1.5e3 m");
+        assert_eq!(format!("{:>12.1e}|", Meters::new(1_500.0)), "     1.5e3 m|");
+    }
+
+    #[test]
+    fn scaled_unit_info() {
+        use crate::system::imperial::{CubicFeetPerSecond, GallonsPerMinute};
+        let q = GallonsPerMinute::new(450.0);
+        assert_eq!(format!("{q}"), "450.00 gpm");
+        assert_eq!(format!("{:.3}", q.into_unit::<CubicFeetPerSecond>()), "1.003 ft^3/s");
+        assert_eq!(format!("{:.1}", Velocity::<SI>::new(2.5)), "2.5 m/s");
+    }
+
+    #[test]
+    fn scaled_into_unit() {
+        let km = Kilometers::new(1.5);
+        assert_eq!(km.into_unit::<Meters>(), Meters::new(1_500.0));
+        assert_eq!(Meters::new(1_500.0).into_unit::<Kilometers>().raw_value(), &1_500.0);
+        approx::assert_relative_eq!(*km.into_unit::<Yards>().raw_value(), 1_500.0 / 0.3048);
+    }
+
+    #[test]
+    fn const_construction() {
+        const KM: Qnty<Kilometers> = <Qnty<Kilometers>>::from_value(1.5);
+        const M: f64 = KM.const_value_in::<Meters>();
+        const SECONDS: Qnty<Seconds, f32> = Seconds::new(90.0);
+        assert_eq!(KM.raw_value(), &1_500.0);
+        assert_eq!(M, 1_500.0);
+        assert_eq!(KM.const_value(), 1.5);
+        assert_eq!(SECONDS.const_value_in::<Seconds>(), 90.0);
+
+        const TABLE: [Qnty<Yards, i32>; 3] = [
+            <Qnty<Yards, i32>>::from_value(1), 
+            <Qnty<Yards, i32>>::from_value(2), 
+            <Qnty<Yards, i32>>::from_value(-3),
+        ];
+        assert_eq!(TABLE.map(|y| *y.raw_value()), [3, 6, -9]);
+        assert_eq!(TABLE[2].const_value_in::<Meters>(), -2);
+        assert_eq!(<Qnty<Feet, i32>>::from_value(5).const_value_in::<Yards>(), 1);
+        // out of range results saturate
+        assert_eq!(<Qnty<Kilometers, i16>>::from_value(100).raw_value(), &i16::MAX);
+    }
+
+    #[test]
+    fn in_system() {
+        use crate::system::{imperial, us_engineering};
+        let speed = Velocity::<imperial::System>::new(10.0);
+        let si: Qnty<Velocity<SI>> = speed.in_system::<SI>();
+        approx::assert_relative_eq!(*si.raw_value(), 3.048);
+
+        // scaled units are re-expressed in the base units of the new system
+        let km = Kilometers::new(1.5).in_system::<imperial::System>();
+        approx::assert_relative_eq!(*km.raw_value(), 1_500.0 / 0.3048);
+        assert_eq!(Yards::new(2.0).in_system::<imperial::System>(), Feet::new(6.0));
+
+        fn normalize<U: crate::unit::Unit>(q: Qnty<U>) -> Qnty<crate::unit::SystemUnit<us_engineering::System, U::Dim>>
+        where
+            crate::unit::GetSystemUnit<U>: crate::conversion::ConversionTo<crate::unit::SystemUnit<us_engineering::System, U::Dim>>
+        {
+            q.in_system::<us_engineering::System>()
+        }
+        assert_eq!(normalize(Yards::new(1.0)), us_engineering::Feet::new(3.0));
+    }
+
+    #[test]
+    fn scaled_value() {
+        let km = Kilometers::new(3.0);
+        assert_eq!(km.raw_value(), &3_000.0);
+        assert_eq!(km.value(), 3.0);
+        assert_eq!(km.value_in::<Meters>(), 3_000.0);
+        assert_eq!(format!("{}", km), "3.00 km");
+        assert_eq!(format!("{:.0}", Yards::new(4.0)), "4 yd");
+        assert_eq!(Hours::new(2_u32).value_in::<Minutes>(), 120);
+        assert_eq!(format!("{}", Hours::new(2_u32)), "2 h");
+        assert_eq!(Feet::new(6.0).value(), 6.0);
+    }
+
+    #[test]
+    fn vec_into_unit() {
+        let readings = Qnty::<Feet, Vec<f64>>::from_raw_value(vec![1.0, 10.0, 100.0]);
+        let ptr = readings.raw_value().as_ptr();
+        let meters = readings.into_unit::<Meters>();
+        approx::assert_relative_eq!(meters.raw_value()[..], [0.3048, 3.048, 30.48][..]);
+        // converted in place
+        assert_eq!(meters.raw_value().as_ptr(), ptr);
+    }
 }
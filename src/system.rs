@@ -1,5 +1,10 @@
+use std::ops::Sub;
+
+use typenum::{Diff, consts::*};
+
 use crate::{
     base_unit::*,
+    conversion::{Conversion, ConversionTo},
     dimension::*,
     unit::*,
 };
@@ -7,8 +12,27 @@ use crate::{
 pub type Mass<S> = SystemUnit<S, MassDimension>;
 pub type Length<S> = SystemUnit<S, LengthDimension>;
 pub type Area<S> = SystemUnit<S, AreaDimension>;
+pub type Volume<S> = SystemUnit<S, VolumeDimension>;
 pub type Time<S> = SystemUnit<S, TimeDimension>;
 pub type Velocity<S> = SystemUnit<S, VelocityDimension>;
+pub type Flow<S> = SystemUnit<S, FlowDimension>;
+pub type FlowRate<S> = Flow<S>;
+pub type Acceleration<S> = SystemUnit<S, AccelerationDimension>;
+pub type Force<S> = SystemUnit<S, ForceDimension>;
+pub type Energy<S> = SystemUnit<S, EnergyDimension>;
+pub type Power<S> = SystemUnit<S, PowerDimension>;
+
+/// A system whose defining constants are all 1 (e.g. c = ħ = 1), so that 
+/// quantities of dimension `D` can be re-expressed in a reduced dimension. 
+/// 
+/// Since the constants are exactly 1 in the system's base units, collapsing 
+/// does not change the raw value (see [`Qnty::collapse`](crate::Qnty::collapse)).
+pub trait CollapseDimension<D> {
+    /// Dimension of `D` once the defining constants are applied
+    type Output;
+}
+
+pub type Collapsed<S, D> = <S as CollapseDimension<D>>::Output;
 
 pub mod si {
     use super::*;
@@ -21,11 +45,169 @@ pub mod si {
 
     pub type Meters = Length<System>;
     pub type Centimeters = ScaledUnit<Meters, 1, 1000>;
+    impl ScaledUnitInfo for Centimeters {
+        const NAME: Info = "centimeter";
+        const SYMBOL: Info = "cm";
+        const PREFIX: Option<i8> = Some(-2);
+    }
+
     pub type Kilometers = ScaledUnit<Meters, 1000>;
+    impl ScaledUnitInfo for Kilometers {
+        const NAME: Info = "kilometer";
+        const SYMBOL: Info = "km";
+        const PREFIX: Option<i8> = Some(3);
+    }
+
+    pub type Fathoms = ScaledUnit<Meters, 1143, 625>;
+    impl ScaledUnitInfo for Fathoms {
+        const NAME: Info = "fathom";
+        const SYMBOL: Info = "ftm";
+    }
+
+    pub type NauticalMiles = ScaledUnit<Meters, 1852>;
+    impl ScaledUnitInfo for NauticalMiles {
+        const NAME: Info = "nautical mile";
+        const SYMBOL: Info = "nmi";
+    }
+
+    pub type AstronomicalUnits = ScaledUnit<Meters, 149_597_870_700>;
+    impl ScaledUnitInfo for AstronomicalUnits {
+        const NAME: Info = "astronomical unit";
+        const SYMBOL: Info = "au";
+    }
+
+    pub type LightYears = ScaledUnit<Meters, 9_460_730_472_580_800>;
+    impl ScaledUnitInfo for LightYears {
+        const NAME: Info = "light-year";
+        const SYMBOL: Info = "ly";
+    }
+
+    /// Lengths measured in [`ParsecBaseUnit`](length::ParsecBaseUnit)s, which are 
+    /// not a whole number of meters
+    type ParsecSystem = MakeSystem<mass::KilogramBaseUnit, length::ParsecBaseUnit, time::SecondBaseUnit>;
+
+    /// The parsec (648 000/π astronomical units) as a length of this system, 
+    /// converted with the exact factor of [`ParsecBaseUnit`](length::ParsecBaseUnit)
+    pub struct Parsec;
+
+    impl Unit for Parsec {
+        type System = System;
+        type Dim = LengthDimension;
+    }
+
+    impl<S2, D2> ConversionTo<SystemUnit<S2, D2>> for Parsec
+    where
+        Length<ParsecSystem>: ConversionTo<SystemUnit<S2, D2>>
+    {
+        type Factor = Conversion<Length<ParsecSystem>, SystemUnit<S2, D2>>;
+    }
+
+    impl<S1, D1> ConversionTo<Parsec> for SystemUnit<S1, D1>
+    where
+        SystemUnit<S1, D1>: ConversionTo<Length<ParsecSystem>>
+    {
+        type Factor = Conversion<SystemUnit<S1, D1>, Length<ParsecSystem>>;
+    }
+
+    pub type Parsecs = ScaledUnit<Parsec, 1>;
+    impl ScaledUnitInfo for Parsecs {
+        const NAME: Info = "parsec";
+        const SYMBOL: Info = "pc";
+    }
+
+    pub type MetersPerSecond = Velocity<System>;
+    pub type Knots = ScaledUnit<MetersPerSecond, 1852, 3600>;
+    impl ScaledUnitInfo for Knots {
+        const NAME: Info = "knot";
+        const SYMBOL: Info = "kn";
+    }
+
+    pub type MillimetersPerHour = ScaledUnit<MetersPerSecond, 1, 3_600_000>;
+    impl ScaledUnitInfo for MillimetersPerHour {
+        const NAME: Info = "millimeter per hour";
+        const SYMBOL: Info = "mm/hr";
+    }
+
+    pub type Liters = ScaledUnit<Volume<System>, 1, 1000>;
+    impl ScaledUnitInfo for Liters {
+        const NAME: Info = "liter";
+        const SYMBOL: Info = "L";
+        const PREFIX: Option<i8> = Some(0);
+    }
+
+    pub type CubicMetersPerSecond = Flow<System>;
+
+    pub type LitersPerSecond = ScaledUnit<Flow<System>, 1, 1000>;
+    impl ScaledUnitInfo for LitersPerSecond {
+        const NAME: Info = "liter per second";
+        const SYMBOL: Info = "L/s";
+    }
 
     pub type Seconds = Time<System>;
     pub type Minutes = ScaledUnit<Seconds, 60>;
+    impl ScaledUnitInfo for Minutes {
+        const NAME: Info = "minute";
+        const SYMBOL: Info = "min";
+    }
+
     pub type Hours = ScaledUnit<Minutes, 60>;
+    impl ScaledUnitInfo for Hours {
+        const NAME: Info = "hour";
+        const SYMBOL: Info = "h";
+    }
+
+    pub type Days = ScaledUnit<Hours, 24>;
+    impl ScaledUnitInfo for Days {
+        const NAME: Info = "day";
+        const SYMBOL: Info = "d";
+    }
+
+    pub type Weeks = ScaledUnit<Days, 7>;
+    impl ScaledUnitInfo for Weeks {
+        const NAME: Info = "week";
+        const SYMBOL: Info = "wk";
+    }
+
+    pub type JulianYears = ScaledUnit<Days, 1461, 4>;
+    impl ScaledUnitInfo for JulianYears {
+        const NAME: Info = "julian year";
+        const SYMBOL: Info = "a";
+    }
+
+    pub type CommonYears = ScaledUnit<Days, 365>;
+    impl ScaledUnitInfo for CommonYears {
+        const NAME: Info = "common year";
+        const SYMBOL: Info = "a_c";
+    }
+
+    pub type LeapYears = ScaledUnit<Days, 366>;
+    impl ScaledUnitInfo for LeapYears {
+        const NAME: Info = "leap year";
+        const SYMBOL: Info = "a_l";
+    }
+
+    pub type Grams = ScaledUnit<Mass<System>, 1, 1000>;
+    impl ScaledUnitInfo for Grams {
+        const NAME: Info = "gram";
+        const SYMBOL: Info = "g";
+        const PREFIX: Option<i8> = Some(0);
+    }
+
+    pub type Newtons = Force<System>;
+    pub type Joules = Energy<System>;
+    pub type Watts = ScaledUnit<Power<System>, 1>;
+    impl ScaledUnitInfo for Watts {
+        const NAME: Info = "watt";
+        const SYMBOL: Info = "W";
+        const PREFIX: Option<i8> = Some(0);
+    }
+    /// Weight of one kilogram under standard gravity (9.80665 m/s²)
+    pub type KilogramsForce = ScaledUnit<Newtons, 196133, 20000>;
+    impl ScaledUnitInfo for KilogramsForce {
+        const NAME: Info = "kilogram-force";
+        const SYMBOL: Info = "kgf";
+        const PLURAL: Option<Info> = Some("kilograms-force");
+    }
 
     #[test]
     fn conversions() {
@@ -35,7 +217,25 @@ pub mod si {
 
         assert_eq!(Conversion::<Meters, Kilometers>::REAL, 1.0/1_000.0);
 
+        assert_eq!(Conversion::<NauticalMiles, Meters>::REAL, 1_852.0);
+        approx::assert_relative_eq!(Conversion::<Knots, MetersPerSecond>::REAL, 1852.0 / 3600.0);
+        approx::assert_relative_eq!(Conversion::<Parsecs, LightYears>::REAL, 3.261_563_777_167_433_4);
+        approx::assert_relative_eq!(Conversion::<Parsecs, AstronomicalUnits>::REAL, 648_000.0 / std::f64::consts::PI);
+        assert!(!factor::<Parsecs, Meters>().is_exact());
+        assert_eq!(factor::<Parsecs, Meters>(), factor::<Length<ParsecSystem>, Meters>());
+        assert_eq!(Parsecs::new(1.0) + Meters::new(0.0), Parsecs::new(1.0).into_unit::<Meters>());
+
         assert_eq!(Conversion::<Hours, Seconds>::REAL, 3_600.0);
+        assert_eq!(Conversion::<Days, Hours>::REAL, 24.0);
+        assert_eq!(Conversion::<Weeks, Days>::REAL, 7.0);
+        assert_eq!(Conversion::<JulianYears, Days>::REAL, 365.25);
+        assert_eq!(Conversion::<LeapYears, CommonYears>::REAL, 366.0 / 365.0);
+
+        assert_eq!(Conversion::<KilogramsForce, Newtons>::REAL, 9.80665);
+
+        assert_eq!(Conversion::<LitersPerSecond, CubicMetersPerSecond>::REAL, 1.0 / 1_000.0);
+        approx::assert_relative_eq!(Conversion::<CubicMetersPerSecond, imperial::CubicFeetPerSecond>::REAL, 35.314_666_721_488_59, max_relative = 1e-15);
+        approx::assert_relative_eq!(Conversion::<imperial::InchesPerHour, MillimetersPerHour>::REAL, 25.4);
     }
 }
 
@@ -48,20 +248,367 @@ pub mod imperial {
         time::SecondBaseUnit
     >;
 
+    pub type Slugs = Mass<System>;
+
     pub type Feet = Length<System>;
+    pub type Inches = ScaledUnit<Feet, 1, 12>;
+    impl ScaledUnitInfo for Inches {
+        const NAME: Info = "inch";
+        const SYMBOL: Info = "in";
+    }
+
     pub type Yards = ScaledUnit<Feet, 3>;
+    impl ScaledUnitInfo for Yards {
+        const NAME: Info = "yard";
+        const SYMBOL: Info = "yd";
+    }
+
+    pub type Chains = ScaledUnit<Feet, 66>;
+    impl ScaledUnitInfo for Chains {
+        const NAME: Info = "chain";
+        const SYMBOL: Info = "ch";
+    }
+
+    pub type Furlongs = ScaledUnit<Feet, 660>;
+    impl ScaledUnitInfo for Furlongs {
+        const NAME: Info = "furlong";
+        const SYMBOL: Info = "fur";
+    }
+
     pub type Miles = ScaledUnit<Feet, 5_280>;
+    impl ScaledUnitInfo for Miles {
+        const NAME: Info = "mile";
+        const SYMBOL: Info = "mi";
+    }
+
+    pub type Fathoms = ScaledUnit<Feet, 6>;
+    impl ScaledUnitInfo for Fathoms {
+        const NAME: Info = "fathom";
+        const SYMBOL: Info = "ftm";
+    }
+
+    pub type NauticalMiles = ScaledUnit<Feet, 2_315_000, 381>; // 1852 m / 0.3048 m
+    impl ScaledUnitInfo for NauticalMiles {
+        const NAME: Info = "nautical mile";
+        const SYMBOL: Info = "nmi";
+    }
+
+    pub type FeetPerSecond = Velocity<System>;
+    pub type Knots = ScaledUnit<FeetPerSecond, 2_315_000, 1_371_600>; // 1852 m/h / 0.3048 m
+    impl ScaledUnitInfo for Knots {
+        const NAME: Info = "knot";
+        const SYMBOL: Info = "kn";
+    }
+
+    pub type InchesPerHour = ScaledUnit<FeetPerSecond, 1, 43_200>;
+    impl ScaledUnitInfo for InchesPerHour {
+        const NAME: Info = "inch per hour";
+        const SYMBOL: Info = "in/hr";
+    }
+
+    /// US liquid gallon (231 in³)
+    pub type Gallons = ScaledUnit<Volume<System>, 231, 1_728>;
+    impl ScaledUnitInfo for Gallons {
+        const NAME: Info = "gallon";
+        const SYMBOL: Info = "gal";
+    }
+
+    pub type AcreFeet = ScaledUnit<Volume<System>, 43_560>;
+    impl ScaledUnitInfo for AcreFeet {
+        const NAME: Info = "acre-foot";
+        const SYMBOL: Info = "ac-ft";
+        const PLURAL: Option<Info> = Some("acre-feet");
+    }
+
+    pub type CubicFeetPerSecond = Flow<System>;
+
+    pub type GallonsPerMinute = ScaledUnit<Flow<System>, 231, 103_680>;
+    impl ScaledUnitInfo for GallonsPerMinute {
+        const NAME: Info = "gallon per minute";
+        const SYMBOL: Info = "gpm";
+    }
+
+    pub type MillionGallonsPerDay = ScaledUnit<Flow<System>, 231_000_000, 149_299_200>;
+    impl ScaledUnitInfo for MillionGallonsPerDay {
+        const NAME: Info = "million gallons per day";
+        const SYMBOL: Info = "MGD";
+        const PLURAL: Option<Info> = Some("million gallons per day");
+    }
+
+    pub type AcreFeetPerDay = ScaledUnit<Flow<System>, 43_560, 86_400>;
+    impl ScaledUnitInfo for AcreFeetPerDay {
+        const NAME: Info = "acre-foot per day";
+        const SYMBOL: Info = "ac-ft/d";
+        const PLURAL: Option<Info> = Some("acre-feet per day");
+    }
 
     pub type Seconds = Time<System>;
     pub type Minutes = ScaledUnit<Seconds, 60>;
+    impl ScaledUnitInfo for Minutes {
+        const NAME: Info = "minute";
+        const SYMBOL: Info = "min";
+    }
+
     pub type Hours = ScaledUnit<Minutes, 60>;
+    impl ScaledUnitInfo for Hours {
+        const NAME: Info = "hour";
+        const SYMBOL: Info = "h";
+    }
+
+    pub type Days = ScaledUnit<Hours, 24>;
+    impl ScaledUnitInfo for Days {
+        const NAME: Info = "day";
+        const SYMBOL: Info = "d";
+    }
+
+    pub type Weeks = ScaledUnit<Days, 7>;
+    impl ScaledUnitInfo for Weeks {
+        const NAME: Info = "week";
+        const SYMBOL: Info = "wk";
+    }
+
+    pub type JulianYears = ScaledUnit<Days, 1461, 4>;
+    impl ScaledUnitInfo for JulianYears {
+        const NAME: Info = "julian year";
+        const SYMBOL: Info = "a";
+    }
+
+    pub type CommonYears = ScaledUnit<Days, 365>;
+    impl ScaledUnitInfo for CommonYears {
+        const NAME: Info = "common year";
+        const SYMBOL: Info = "a_c";
+    }
+
+    pub type LeapYears = ScaledUnit<Days, 366>;
+    impl ScaledUnitInfo for LeapYears {
+        const NAME: Info = "leap year";
+        const SYMBOL: Info = "a_l";
+    }
+
+    /// 1 slug accelerated at 1 ft/s²
+    pub type PoundsForce = Force<System>;
 
     #[test]
     fn conversions() {
         use crate::conversion::*;
         assert_eq!(Conversion::<Feet, Yards>::REAL, 1.0/3.0);
         assert_eq!(Conversion::<Miles, Feet>::REAL, 5_280.0);
+        assert_eq!(Conversion::<Miles, Furlongs>::REAL, 8.0);
+        assert_eq!(Conversion::<Furlongs, Chains>::REAL, 10.0);
+        assert_eq!(Conversion::<Fathoms, Feet>::REAL, 6.0);
+
+        approx::assert_relative_eq!(Conversion::<Knots, si::Knots>::REAL, 1.0);
+        approx::assert_relative_eq!(Conversion::<NauticalMiles, si::NauticalMiles>::REAL, 1.0);
+        approx::assert_relative_eq!(Conversion::<Fathoms, si::Fathoms>::REAL, 1.0);
+
+        approx::assert_relative_eq!(Conversion::<CubicFeetPerSecond, GallonsPerMinute>::REAL, 448.831_168_831_168_8);
+        approx::assert_relative_eq!(Conversion::<MillionGallonsPerDay, CubicFeetPerSecond>::REAL, 1.547_228_652_263_374_5);
+        approx::assert_relative_eq!(Conversion::<AcreFeetPerDay, CubicFeetPerSecond>::REAL, 0.504_166_666_666_666_7);
 
         assert_eq!(Conversion::<Hours, Seconds>::REAL, 3_600.0);
+        assert_eq!(Conversion::<Days, Hours>::REAL, 24.0);
+        assert_eq!(Conversion::<Weeks, Days>::REAL, 7.0);
+        assert_eq!(Conversion::<JulianYears, Days>::REAL, 365.25);
+        assert_eq!(Conversion::<LeapYears, CommonYears>::REAL, 366.0 / 365.0);
+    }
+}
+
+/// US customary engineering units (lbm, ft, s), with pound-force applied 
+/// through the gravitational constant g<sub>c</sub>.
+/// 
+/// Since pound-mass is the base mass, the coherent unit of force is the 
+/// poundal (lbm·ft/s²). [`PoundsForce`](us_engineering::PoundsForce) is 
+/// scaled from it by g<sub>c</sub> = 9.806 65/0.3048 ≈ 32.174 lbm·ft/(lbf·s²), so 
+/// `lbm * ft/s²` quantities convert to and from lbf without carrying g<sub>c</sub> by hand.
+pub mod us_engineering {
+    use super::*;
+
+    pub type System = MakeSystem<
+        mass::PoundMassBaseUnit,
+        length::FootBaseUnit,
+        time::SecondBaseUnit
+    >;
+
+    pub type PoundsMass = Mass<System>;
+
+    pub type Feet = Length<System>;
+
+    pub type Seconds = Time<System>;
+
+    pub type Poundals = Force<System>;
+    pub type PoundsForce = ScaledUnit<Poundals, 196_133, 6_096>;
+    impl ScaledUnitInfo for PoundsForce {
+        const NAME: Info = "pound-force";
+        const SYMBOL: Info = "lbf";
+        const PLURAL: Option<Info> = Some("pounds-force");
+    }
+
+    #[test]
+    fn conversions() {
+        use crate::conversion::*;
+        assert_eq!(Conversion::<PoundsForce, Poundals>::NUM, 196_133);
+        assert_eq!(Conversion::<PoundsForce, Poundals>::DEN, 6_096);
+        approx::assert_relative_eq!(Conversion::<PoundsForce, imperial::PoundsForce>::REAL, 1.0);
+        approx::assert_relative_eq!(Conversion::<PoundsMass, imperial::Slugs>::REAL, 6_096.0 / 196_133.0);
+
+        // 1 lbf = 0.45359237 kg * 9.80665 m/s^2, exactly
+        approx::assert_relative_eq!(Conversion::<PoundsForce, si::Newtons>::REAL, 4.448_221_615_260_5);
+        assert_eq!(Conversion::<PoundsForce, si::Newtons>::NUM, 8_896_443_230_521);
+        assert_eq!(Conversion::<PoundsForce, si::Newtons>::DEN, 2_000_000_000_000);
+    }
+}
+
+/// Technical (gravitational) metric system, in which the kilogram-force 
+/// is the coherent unit of force. 
+/// 
+/// The base mass is the [`hyl`](mass::HylBaseUnit), so that 1 kgf accelerates 
+/// 1 hyl at 1 m/s².
+pub mod technical {
+    use super::*;
+
+    pub type System = MakeSystem<
+        mass::HylBaseUnit,
+        length::MeterBaseUnit,
+        time::SecondBaseUnit
+    >;
+
+    pub type Hyls = Mass<System>;
+
+    pub type Meters = Length<System>;
+
+    pub type Seconds = Time<System>;
+
+    pub type KilogramsForce = Force<System>;
+
+    #[test]
+    fn conversions() {
+        use crate::conversion::*;
+        approx::assert_relative_eq!(Conversion::<KilogramsForce, si::KilogramsForce>::REAL, 1.0);
+        approx::assert_relative_eq!(Conversion::<KilogramsForce, si::Newtons>::REAL, 9.80665);
+        approx::assert_relative_eq!(Conversion::<Hyls, Mass<si::System>>::REAL, 9.80665);
+    }
+}
+
+/// Natural units, where c = ħ = 1 and every quantity collapses to a power of 
+/// the electronvolt. 
+/// 
+/// Mass, length and time keep their own dimension until 
+/// [`collapse`](crate::Qnty::collapse)d: a quantity of dimension 
+/// M<sup>a</sup>L<sup>b</sup>T<sup>c</sup> becomes eV<sup>a-b-c</sup>.
+/// ```
+/// # use furlong::system::{Energy, natural::{self, ElectronVolts}, si::Joules};
+/// let rest_energy = Joules::new(8.187_105_776_823_886e-14)
+///     .into_unit::<Energy<natural::System>>()
+///     .collapse();
+/// let _: furlong::Qnty<ElectronVolts> = rest_energy;
+/// approx::assert_relative_eq!(*rest_energy.raw_value(), 510_998.950, max_relative = 1e-9);
+/// ```
+pub mod natural {
+    use super::*;
+
+    pub type System = MakeSystem<
+        mass::ElectronVoltMassBaseUnit,
+        length::NaturalLengthBaseUnit,
+        time::NaturalTimeBaseUnit
+    >;
+
+    pub type ElectronVolts = SystemUnit<System, Dimension<P1>>;
+    pub type InverseElectronVolts = SystemUnit<System, Dimension<N1>>;
+
+    impl<D> CollapseDimension<D> for System
+    where
+        D: DimPart<MassBaseDimension> + DimPart<LengthBaseDimension> + DimPart<TimeBaseDimension>,
+        GetDimPart<D, MassBaseDimension>: Sub<GetDimPart<D, LengthBaseDimension>>,
+        Diff<GetDimPart<D, MassBaseDimension>, GetDimPart<D, LengthBaseDimension>>: Sub<GetDimPart<D, TimeBaseDimension>>,
+    {
+        type Output = Dimension<
+            Diff<Diff<GetDimPart<D, MassBaseDimension>, GetDimPart<D, LengthBaseDimension>>, GetDimPart<D, TimeBaseDimension>>
+        >;
+    }
+
+    #[test]
+    fn conversions() {
+        use crate::qnty::Qnty;
+
+        let length = si::Meters::new(1.973_269_804_593_025e-7)
+            .into_unit::<Length<System>>()
+            .collapse();
+        let _: Qnty<InverseElectronVolts> = length;
+        approx::assert_relative_eq!(*length.raw_value(), 1.0, max_relative = 1e-12);
+
+        let time = si::Seconds::new(6.582_119_569_509_066e-16)
+            .into_unit::<Time<System>>()
+            .collapse();
+        let _: Qnty<InverseElectronVolts> = time;
+        approx::assert_relative_eq!(*time.raw_value(), 1.0, max_relative = 1e-12);
+
+        let mass = ElectronVolts::new(1.0)
+            .expand::<MassDimension>()
+            .into_unit::<Mass<si::System>>();
+        approx::assert_relative_eq!(*mass.raw_value(), 1.782_661_921_627_898e-36, max_relative = 1e-12);
+
+        // c = 1
+        let c = si::MetersPerSecond::new(299_792_458.0)
+            .into_unit::<Velocity<System>>()
+            .collapse();
+        approx::assert_relative_eq!(*c.raw_value(), 1.0, max_relative = 1e-12);
+    }
+}
+
+/// Planck units, where c = ħ = G = 1 and every quantity collapses to a 
+/// dimensionless number.
+pub mod planck {
+    use super::*;
+
+    pub type System = MakeSystem<
+        mass::PlanckMassBaseUnit,
+        length::PlanckLengthBaseUnit,
+        time::PlanckTimeBaseUnit
+    >;
+
+    impl<D> CollapseDimension<D> for System {
+        type Output = Dimension;
+    }
+
+    #[test]
+    fn conversions() {
+        use crate::qnty::Qnty;
+
+        let c = si::MetersPerSecond::new(299_792_458.0)
+            .into_unit::<Velocity<System>>()
+            .collapse();
+        let _: Qnty<SystemUnit<System, Dimension>> = c;
+        approx::assert_relative_eq!(*c.raw_value(), 1.0, max_relative = 1e-6);
+
+        let length = SystemUnit::<System, Dimension>::new(1.0)
+            .expand::<LengthDimension>()
+            .into_unit::<si::Meters>();
+        approx::assert_relative_eq!(*length.raw_value(), 1.616_255e-35);
+    }
+}
+
+/// Hartree atomic units, where ħ = m<sub>e</sub> = a<sub>0</sub> = 1. 
+/// 
+/// Mass, length and time are each fixed by a defining constant, so no 
+/// dimensions collapse.
+pub mod atomic {
+    use super::*;
+
+    pub type System = MakeSystem<
+        mass::ElectronMassBaseUnit,
+        length::BohrRadiusBaseUnit,
+        time::AtomicTimeBaseUnit
+    >;
+
+    pub type ElectronMasses = Mass<System>;
+    pub type Bohrs = Length<System>;
+    pub type Hartrees = Energy<System>;
+
+    #[test]
+    fn conversions() {
+        use crate::conversion::*;
+        approx::assert_relative_eq!(Conversion::<Bohrs, si::Meters>::REAL, 5.291_772_109_03e-11, max_relative = 1e-12);
+        approx::assert_relative_eq!(Conversion::<ElectronMasses, Mass<si::System>>::REAL, 9.109_383_701_5e-31, max_relative = 1e-12);
+        approx::assert_relative_eq!(Conversion::<Hartrees, si::Joules>::REAL, 4.359_744_722_207_1e-18, max_relative = 1e-11);
     }
 }
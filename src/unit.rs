@@ -5,12 +5,20 @@ use typenum::{Integer, Diff, Sum, ATerm, TArr, tarr};
 use crate::{
     conversion::*,
     dimension::*,
+    format::{UnitFormat, UnitPart},
     qnty::Qnty,
 };
 
 pub trait BaseUnitTag {
     /// BaseDimension of this BaseUnit
     type Dimension: BaseDimension;
+
+    /// Conversion to the canonical base unit of [`BaseUnitTag::Dimension`]
+    /// (i.e. how many grams, meters or seconds are in 1 of Self). 
+    /// 
+    /// Conversions between any two base units of the same dimension, in either 
+    /// direction, are derived from this factor.
+    type Canonical: ConversionFactor;
 }
 
 pub trait BaseUnit {
@@ -27,37 +35,24 @@ impl<B: BaseUnitTag> BaseUnit for B {
     type Scale = ConvInt<1>;
 }
 
-impl<B: BaseUnitTag> ConversionTo<B> for B {
-    type Factor = ConvInt<1>;
-}
-
-pub struct ScaledBaseUnit<B, const N: u32, const D: u32 = 1> {
+pub struct ScaledBaseUnit<B, const N: u64, const D: u64 = 1> {
     base: PD<B>,
 }
 
-impl<B: BaseUnit, const N: u32, const D: u32> BaseUnit for ScaledBaseUnit<B, N, D>  {
+impl<B: BaseUnit, const N: u64, const D: u64> BaseUnit for ScaledBaseUnit<B, N, D>  {
     type Base = <B as BaseUnit>::Base;
     type Scale = ConvProd<ConvRatio<N,D>,<B as BaseUnit>::Scale>;
 }
 
-impl<B1: BaseUnit, const N: u32, const D: u32, B2: BaseUnitTag> ConversionTo<B2> for ScaledBaseUnit<B1, N, D>
-where <B1 as BaseUnit>::Base: ConversionTo<B2> {
-    type Factor = ConvProd<<Self as BaseUnit>::Scale, Conversion<<B1 as BaseUnit>::Base, B2>>;
-}
-
-impl<B1: BaseUnitTag, const N: u32, const D: u32, B2: BaseUnit> ConversionTo<ScaledBaseUnit<B2, N, D>> for B1
-where B1: ConversionTo<<B2 as BaseUnit>::Base> {
-    type Factor = ConvQuot<Conversion<B1, <B2 as BaseUnit>::Base>, <ScaledBaseUnit<B2, N, D> as BaseUnit>::Scale>;
-}
+/// Conversion from a base unit to the canonical base unit of its dimension
+pub type ToCanonical<B> = ConvProd<<B as BaseUnit>::Scale, <<B as BaseUnit>::Base as BaseUnitTag>::Canonical>;
 
-impl<
-    B1: BaseUnit, const N1: u32, const D1: u32, 
-    B2: BaseUnit, const N2: u32, const D2: u32
-> ConversionTo<ScaledBaseUnit<B2, N2, D2>> for ScaledBaseUnit<B1, N1, D1>
+/// Any two base units of the same dimension convert through the dimension's canonical base unit
+impl<B1: BaseUnit, B2: BaseUnit> ConversionTo<B2> for B1
 where
-    B1: ConversionTo<B2>
+    <B2 as BaseUnit>::Base: BaseUnitTag<Dimension = <<B1 as BaseUnit>::Base as BaseUnitTag>::Dimension>
 {
-    type Factor = ConvQuot<ConvProd<<Self as BaseUnit>::Scale, Conversion<B1,B2>>,<ScaledBaseUnit<B2, N2, D2> as BaseUnit>::Scale>;
+    type Factor = ConvQuot<ToCanonical<B1>, ToCanonical<B2>>;
 }
 
 pub type Info = &'static str;
@@ -65,15 +60,54 @@ pub type Info = &'static str;
 pub trait BaseUnitInfo: BaseUnit {
     const NAME: Info;
     const SYMBOL: Info;
+
+    /// Exponent of the SI prefix already in [`BaseUnitInfo::SYMBOL`] (e.g. `Some(3)` for "kg"), 
+    /// or `None` if this unit does not take SI prefixes
+    const PREFIX: Option<i8> = None;
+
+    /// English plural of [`BaseUnitInfo::NAME`] if it does not just add "s" or "es" (e.g. "feet")
+    const PLURAL: Option<Info> = None;
 }
 
 pub trait Unit: Sized {
     type System;
     type Dim;
+
+    /// Symbol shown by the `Debug` output of a [`Qnty`] in this unit. 
+    /// 
+    /// Set it on units that implement `Unit` themselves; the units defined in 
+    /// [`system`](crate::system) are found among the builtin parse symbols instead. 
+    const SYMBOL: Option<Info> = None;
 }
 
 pub trait UnitInfo: Unit {
+    /// Compact abbreviation, e.g. `kgm^2s^-2`
     fn abbr() -> String;
+
+    /// The named units that make up this unit, with their powers. 
+    /// 
+    /// Empty if they are not known, in which case [`UnitInfo::format`] writes 
+    /// [`UnitInfo::abbr`] in every style. 
+    fn parts() -> Vec<UnitPart> {
+        Vec::new()
+    }
+
+    /// This unit written in `format`
+    fn format(format: UnitFormat) -> String {
+        let parts = Self::parts();
+        if parts.is_empty() { Self::abbr() } else { format.write(&parts) }
+    }
+}
+
+/// `parts` written compactly, as in [`UnitInfo::abbr`]
+fn compact(parts: &[UnitPart]) -> String {
+    parts.iter()
+        .map(|part| match part.power {
+            0 => String::new(),
+            1 => String::from(part.symbol),
+            n => format!("{}^{}", part.symbol, n),
+        })
+        .collect()
 }
 
 pub trait UnitSystemPart<D: BaseDimension> {
@@ -86,6 +120,27 @@ pub type MakeSystem<MassBase, LengthBase, TimeBase> = tarr![MassBase, LengthBase
 
 pub type Unitless = ATerm;
 
+/// Size of each of a system's base units in grams, meters or seconds (e.g. 
+/// `[14593.9..., 0.3048, 1.0]` for slugs, feet and seconds). Unlike [`BaseUnitInfo`], 
+/// this is known for every system, so that any [`Qnty`] can be debug-printed. 
+pub trait SystemBaseUnits {
+    fn canonical_factors() -> Vec<f64>;
+}
+
+impl SystemBaseUnits for ATerm {
+    fn canonical_factors() -> Vec<f64> {
+        Vec::new()
+    }
+}
+
+impl<B: BaseUnit, Rest: SystemBaseUnits> SystemBaseUnits for TArr<B, Rest> {
+    fn canonical_factors() -> Vec<f64> {
+        let mut factors = vec![<ToCanonical<B> as ConversionFactor>::REAL];
+        factors.extend(Rest::canonical_factors());
+        factors
+    }
+}
+
 impl<BD: BaseDimension, UI, UL> UnitSystemPart<BD> for TArr<UI, UL>
 where
     Self: Item<BD::Ordinal>,
@@ -100,7 +155,7 @@ pub struct SystemUnit<S, D> {
 }
 
 impl<S, D> SystemUnit<S, D> {
-    pub fn new<T>(value: T) -> Qnty<Self, T> {
+    pub const fn new<T>(value: T) -> Qnty<Self, T> {
         Qnty::from_raw_value(value)
     }
 }
@@ -122,30 +177,33 @@ where
     GetDimPart<D, TimeBaseDimension>: Integer,
 {
     fn abbr() -> String {
-        let mass_abbr = <GetBase<S, MassBaseDimension> as BaseUnitInfo>::SYMBOL;
-        let mass_pwr = <GetDimPart<D, MassBaseDimension> as Integer>::I8;
-        let mass_part = match mass_pwr {
-            0 => String::from(""),
-            1 => String::from(mass_abbr),
-            _ => format!("{}^{}", mass_abbr, mass_pwr),
-        };
-
-        let length_abbr = <GetBase<S, LengthBaseDimension> as BaseUnitInfo>::SYMBOL;
-        let length_pwr = <GetDimPart<D, LengthBaseDimension> as Integer>::I8;
-        let length_part = match length_pwr {
-            0 => String::from(""),
-            1 => String::from(length_abbr),
-            _ => format!("{}^{}", length_abbr, length_pwr),
-        };
+        compact(&Self::parts())
+    }
 
-        let time_abbr = <GetBase<S, TimeBaseDimension> as BaseUnitInfo>::SYMBOL;
-        let time_pwr = <GetDimPart<D, TimeBaseDimension> as Integer>::I8;
-        let time_part = match time_pwr {
-            0 => String::from(""),
-            1 => String::from(time_abbr),
-            _ => format!("{}^{}", time_abbr, time_pwr),
-        };
-        format!("{}{}{}", mass_part.as_str(), length_part.as_str(), time_part.as_str())
+    fn parts() -> Vec<UnitPart> {
+        vec![
+            UnitPart {
+                name: <GetBase<S, MassBaseDimension> as BaseUnitInfo>::NAME,
+                symbol: <GetBase<S, MassBaseDimension> as BaseUnitInfo>::SYMBOL,
+                power: <GetDimPart<D, MassBaseDimension> as Integer>::I8,
+                prefix: <GetBase<S, MassBaseDimension> as BaseUnitInfo>::PREFIX,
+                plural: <GetBase<S, MassBaseDimension> as BaseUnitInfo>::PLURAL,
+            },
+            UnitPart {
+                name: <GetBase<S, LengthBaseDimension> as BaseUnitInfo>::NAME,
+                symbol: <GetBase<S, LengthBaseDimension> as BaseUnitInfo>::SYMBOL,
+                power: <GetDimPart<D, LengthBaseDimension> as Integer>::I8,
+                prefix: <GetBase<S, LengthBaseDimension> as BaseUnitInfo>::PREFIX,
+                plural: <GetBase<S, LengthBaseDimension> as BaseUnitInfo>::PLURAL,
+            },
+            UnitPart {
+                name: <GetBase<S, TimeBaseDimension> as BaseUnitInfo>::NAME,
+                symbol: <GetBase<S, TimeBaseDimension> as BaseUnitInfo>::SYMBOL,
+                power: <GetDimPart<D, TimeBaseDimension> as Integer>::I8,
+                prefix: <GetBase<S, TimeBaseDimension> as BaseUnitInfo>::PREFIX,
+                plural: <GetBase<S, TimeBaseDimension> as BaseUnitInfo>::PLURAL,
+            },
+        ]
     }
 }
 
@@ -173,16 +231,59 @@ where
 
 pub type GetSystemUnit<U> = SystemUnit<<U as Unit>::System, <U as Unit>::Dim>;
 
-pub struct ScaledUnit<U, const NUM: u32, const DEN: u32 = 1> {
+pub struct ScaledUnit<U, const NUM: u64, const DEN: u64 = 1> {
     unit: PD<U>
 }
 
-impl<U: Unit, const NUM: u32, const DEN: u32> Unit for ScaledUnit<U, NUM, DEN> {
+impl<U: Unit, const NUM: u64, const DEN: u64> Unit for ScaledUnit<U, NUM, DEN> {
     type System = <U as Unit>::System;
     type Dim = <U as Unit>::Dim;
 }
 
-impl<U: Unit, const NUM: u32, const DEN: u32> ScaledUnit<U, NUM, DEN> {
+/// Name and symbol of a [`ScaledUnit`], which cannot be derived from its
+/// system's base units (e.g. "gpm" rather than "ft^3s^-1")
+pub trait ScaledUnitInfo {
+    const NAME: Info;
+    const SYMBOL: Info;
+
+    /// See [`BaseUnitInfo::PREFIX`]
+    const PREFIX: Option<i8> = None;
+
+    /// See [`BaseUnitInfo::PLURAL`]
+    const PLURAL: Option<Info> = None;
+}
+
+impl<U: Unit, const NUM: u64, const DEN: u64> UnitInfo for ScaledUnit<U, NUM, DEN>
+where
+    Self: ScaledUnitInfo
+{
+    fn abbr() -> String {
+        String::from(<Self as ScaledUnitInfo>::SYMBOL)
+    }
+
+    fn parts() -> Vec<UnitPart> {
+        vec![UnitPart { 
+            name: <Self as ScaledUnitInfo>::NAME, 
+            symbol: <Self as ScaledUnitInfo>::SYMBOL, 
+            power: 1, 
+            prefix: <Self as ScaledUnitInfo>::PREFIX,
+            plural: <Self as ScaledUnitInfo>::PLURAL,
+        }]
+    }
+}
+
+impl<U: Unit, const NUM: u64, const DEN: u64> ScaledUnit<U, NUM, DEN> {
+    /// Create a [`Qnty`] from a value in this unit, which is converted to the 
+    /// base unit of its system. 
+    /// 
+    /// This cannot be a `const fn`: converting any `T` calls [`Convertible::convert`], 
+    /// and trait methods cannot be called in `const` contexts on stable Rust. 
+    /// Quantities of primitive values have a `const` `from_value` instead:
+    /// ```
+    /// # use furlong::{Qnty, system::si::Kilometers};
+    /// const KM: Qnty<Kilometers> = <Qnty<Kilometers>>::from_value(1.5);
+    /// assert_eq!(KM, Kilometers::new(1.5));
+    /// ```
     pub fn new<T: Convertible>(value: T) -> Qnty<Self, T> 
     where U: ConversionTo<GetSystemUnit<U>> {
         Qnty::from_raw_value(value.convert::<Conversion<Self, GetSystemUnit<U>>>())
@@ -209,7 +310,7 @@ impl ConversionTo<SystemUnit<ATerm, ATerm>> for SystemUnit<ATerm, ATerm> {
 }
 
 /// Convert from a scaled unit to the base unit of a system (used with `Unit::new()`)
-impl<U: Unit, const NUM: u32, const DEN: u32, S2, D2> ConversionTo<SystemUnit<S2, D2>> for ScaledUnit<U, NUM, DEN>
+impl<U: Unit, const NUM: u64, const DEN: u64, S2, D2> ConversionTo<SystemUnit<S2, D2>> for ScaledUnit<U, NUM, DEN>
 where 
     U: ConversionTo<SystemUnit<S2, D2>>
 {
@@ -217,7 +318,7 @@ where
 }
 
 /// Convert from the base unit of system to a scaled unit (used with [`Qnty::value`])
-impl<U: Unit, const NUM: u32, const DEN: u32, S1, D1> ConversionTo<ScaledUnit<U, NUM, DEN>> for SystemUnit<S1, D1>
+impl<U: Unit, const NUM: u64, const DEN: u64, S1, D1> ConversionTo<ScaledUnit<U, NUM, DEN>> for SystemUnit<S1, D1>
 where 
     SystemUnit<S1, D1>: ConversionTo<U>
 {
@@ -225,8 +326,8 @@ where
 }
 
 /// Convert between scaled units
-impl<U1, const NUM1: u32, const DEN1: u32,
-     U2, const NUM2: u32, const DEN2: u32> ConversionTo<ScaledUnit<U2, NUM2, DEN2>> for ScaledUnit<U1, NUM1, DEN1>
+impl<U1, const NUM1: u64, const DEN1: u64,
+     U2, const NUM2: u64, const DEN2: u64> ConversionTo<ScaledUnit<U2, NUM2, DEN2>> for ScaledUnit<U1, NUM1, DEN1>
 where
     U1: ConversionTo<U2>
 {
@@ -242,6 +343,14 @@ mod test {
         imperial::{System as Imp, Feet, Yards, Seconds as SecondsIMP, Hours as HoursIMP, Miles}
     };
 
+    #[test]
+    fn base_units() {
+        assert_eq!(<SI as SystemBaseUnits>::canonical_factors(), [1_000.0, 1.0, 1.0]);
+        let imperial = <Imp as SystemBaseUnits>::canonical_factors();
+        approx::assert_relative_eq!(imperial[0], 14_593.902_937_206_364, max_relative = 1e-15);
+        approx::assert_relative_eq!(imperial[1], 0.304_8);
+    }
+
     macro_rules! assert_conv {
         ($val1:literal $U1:ty = $val2:literal $U2:ty) => {
             approx::assert_relative_eq!(<Conversion::<$U1, $U2> as ConversionFactor>::REAL, $val2 as f64 / $val1 as f64, epsilon=f32::EPSILON as f64);
@@ -256,6 +365,35 @@ mod test {
         assert_conv!(1 MeterBaseUnit = 1 MeterBaseUnit);
         assert_conv!(3 FootBaseUnit = 0.9144 MeterBaseUnit);
         assert_conv!(1 YardBaseUnit = 0.9144 MeterBaseUnit);
+        assert_conv!(1 ChainBaseUnit = 22 YardBaseUnit);
+        assert_conv!(1 FurlongBaseUnit = 201.168 MeterBaseUnit);
+        assert_conv!(1 FurlongBaseUnit = 660 FootBaseUnit);
+        assert_conv!(1 NauticalMileBaseUnit = 9.206_235_584_188_34 FurlongBaseUnit);
+        assert_conv!(1 FathomBaseUnit = 2 YardBaseUnit);
+        assert_conv!(1 NauticalMileBaseUnit = 1852 MeterBaseUnit);
+        assert_conv!(1 AstronomicalUnitBaseUnit = 149_597_870_700_i64 MeterBaseUnit);
+        assert_conv!(1 LightYearBaseUnit = 63_241.077_084_266_28 AstronomicalUnitBaseUnit);
+        assert_conv!(1 ParsecBaseUnit = 206_264.806_247_096_36 AstronomicalUnitBaseUnit);
+        // furlong -> chain -> yard -> meter stays exact
+        assert_eq!(<Conversion<FurlongBaseUnit, MeterBaseUnit> as ConversionFactor>::NUM, 25_146);
+        assert_eq!(<Conversion<FurlongBaseUnit, MeterBaseUnit> as ConversionFactor>::DEN, 125);
+
+        use crate::base_unit::mass::*;
+        assert_conv!(1 KilogramBaseUnit = 1_000 GramBaseUnit);
+        assert_conv!(1 HylBaseUnit = 9.80665 KilogramBaseUnit);
+        assert_conv!(1 PoundMassBaseUnit = 453.592_37 GramBaseUnit);
+        assert_conv!(1 SlugBaseUnit = 32.174_048_556_430_45 PoundMassBaseUnit);
+        assert_conv!(1 SlugBaseUnit = 14_593.902_937_206_364 GramBaseUnit);
+        assert_conv!(1 ElectronMassBaseUnit = 9.109_383_701_5e-31 KilogramBaseUnit);
+
+        use crate::base_unit::time::*;
+        assert_conv!(1 HourBaseUnit = 60 MinuteBaseUnit);
+        assert_conv!(1 DayBaseUnit = 86_400 SecondBaseUnit);
+        assert_conv!(1 WeekBaseUnit = 168 HourBaseUnit);
+        assert_conv!(1 JulianYearBaseUnit = 31_557_600 SecondBaseUnit);
+        assert_conv!(4 JulianYearBaseUnit = 1461 DayBaseUnit);
+        assert_conv!(1 CommonYearBaseUnit = 365 DayBaseUnit);
+        assert_conv!(1 LeapYearBaseUnit = 366 DayBaseUnit);
     }
 
     #[test]